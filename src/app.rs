@@ -1,23 +1,47 @@
 use crate::events::Event;
-use crate::system::SystemData;
+use crate::system::diskusage::{self, DiskExplorer};
+use crate::system::procdetail::ProcessDetail;
+use crate::system::procfilter::ProcessFilter;
+use crate::system::proctree::{self, ProcessRow};
+use crate::system::sched::{self, IoClass, IoPriority};
+use crate::system::signal;
+use crate::system::threads::ProcessThreads;
+use crate::system::sockets::Connection;
+use crate::system::{format_uptime, Collections, ProcessColumn, ProcessInfo, ProcessKey, SystemData};
 use crate::ui;
 use anyhow::Result;
 use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEventKind};
 use ratatui::prelude::*;
-use std::time::Duration;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
+/// Mounts predicted to fill within this window are flagged in the Disks view.
+const DEFAULT_DISK_FULL_HORIZON: Duration = Duration::from_secs(24 * 3600);
+
+/// How long "TERM, then KILL" waits for a process to exit on its own.
+const DEFAULT_KILL_GRACE: Duration = Duration::from_secs(5);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TreeNode {
     Overview,
     Cpu,
     Memory,
     Disks,
+    Storage,
     Network,
+    Connections,
+    Routing,
+    Ipc,
+    Firewall,
     Processes,
     Devices,
     Logs,
+    DiskUsage,
+    ProcessDetail,
 }
 
 impl TreeNode {
@@ -27,7 +51,12 @@ impl TreeNode {
             TreeNode::Cpu,
             TreeNode::Memory,
             TreeNode::Disks,
+            TreeNode::Storage,
             TreeNode::Network,
+            TreeNode::Connections,
+            TreeNode::Routing,
+            TreeNode::Ipc,
+            TreeNode::Firewall,
             TreeNode::Processes,
             TreeNode::Devices,
             TreeNode::Logs,
@@ -40,10 +69,17 @@ impl TreeNode {
             TreeNode::Cpu => "CPU",
             TreeNode::Memory => "Memory",
             TreeNode::Disks => "Disks",
+            TreeNode::Storage => "Storage",
             TreeNode::Network => "Network",
+            TreeNode::Connections => "Connections",
+            TreeNode::Routing => "Routing",
+            TreeNode::Ipc => "IPC",
+            TreeNode::Firewall => "Firewall",
             TreeNode::Processes => "Processes",
             TreeNode::Devices => "Devices",
             TreeNode::Logs => "Logs",
+            TreeNode::DiskUsage => "Disk Usage",
+            TreeNode::ProcessDetail => "Process Detail",
         }
     }
 
@@ -53,10 +89,17 @@ impl TreeNode {
             TreeNode::Cpu => "[C]",
             TreeNode::Memory => "[M]",
             TreeNode::Disks => "[D]",
+            TreeNode::Storage => "[B]",
             TreeNode::Network => "[N]",
+            TreeNode::Connections => "[O]",
+            TreeNode::Routing => "[R]",
+            TreeNode::Ipc => "[U]",
+            TreeNode::Firewall => "[F]",
             TreeNode::Processes => "[P]",
             TreeNode::Devices => "[V]",
             TreeNode::Logs => "[L]",
+            TreeNode::DiskUsage => "[E]",
+            TreeNode::ProcessDetail => "[I]",
         }
     }
 
@@ -66,7 +109,12 @@ impl TreeNode {
             'c' => Some(TreeNode::Cpu),
             'm' => Some(TreeNode::Memory),
             'd' => Some(TreeNode::Disks),
+            'b' => Some(TreeNode::Storage),
             'n' => Some(TreeNode::Network),
+            'o' => Some(TreeNode::Connections),
+            'r' => Some(TreeNode::Routing),
+            'u' => Some(TreeNode::Ipc),
+            'f' => Some(TreeNode::Firewall),
             'p' => Some(TreeNode::Processes),
             'v' => Some(TreeNode::Devices),
             'l' => Some(TreeNode::Logs),
@@ -78,8 +126,16 @@ impl TreeNode {
 #[derive(Debug, Clone)]
 pub struct Tab {
     pub node: TreeNode,
-    pub scroll_offset: u16,
+    /// First list row on screen
+    pub scroll_offset: usize,
     pub selected_item: usize,
+    /// Length and visible height of the tab's list as last drawn, so keys
+    /// can clamp and page without knowing the layout
+    pub list_len: Cell<usize>,
+    pub page_height: Cell<usize>,
+    /// Sort column and direction of the process table (Processes tab)
+    pub sort_column: ProcessColumn,
+    pub sort_ascending: bool,
 }
 
 impl Tab {
@@ -88,8 +144,70 @@ impl Tab {
             node,
             scroll_offset: 0,
             selected_item: 0,
+            list_len: Cell::new(0),
+            page_height: Cell::new(0),
+            sort_column: ProcessColumn::Cpu,
+            sort_ascending: false,
         }
     }
+
+    /// Moves the selection for a navigation key. Returns false for keys
+    /// that don't navigate.
+    pub fn navigate(&mut self, key: KeyCode) -> bool {
+        let page = self.page_height.get().max(1);
+        let item = match key {
+            KeyCode::Up | KeyCode::Char('k') => self.selected_item.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected_item.saturating_add(1),
+            KeyCode::PageUp => self.selected_item.saturating_sub(page),
+            KeyCode::PageDown => self.selected_item.saturating_add(page),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => usize::MAX,
+            _ => return false,
+        };
+        self.select(item);
+        true
+    }
+
+    /// Selects `item`, clamped to the list as last drawn, and scrolls just
+    /// enough to keep it on screen.
+    pub fn select(&mut self, item: usize) {
+        let len = self.list_len.get();
+        self.selected_item = item.min(len.saturating_sub(1));
+        self.scroll_offset = self.visible_offset(len, self.page_height.get());
+    }
+
+    /// First row to show of a `len`-row list in `height` rows: the stored
+    /// offset, moved only as far as needed to bring the selection into
+    /// view and not past the end of the list.
+    pub fn visible_offset(&self, len: usize, height: usize) -> usize {
+        let height = height.max(1);
+        let selected = self.selected_item.min(len.saturating_sub(1));
+        let offset = if selected < self.scroll_offset {
+            selected
+        } else if selected >= self.scroll_offset + height {
+            selected + 1 - height
+        } else {
+            self.scroll_offset
+        };
+        offset.min(len.saturating_sub(height))
+    }
+}
+
+/// A nice, I/O priority or CPU affinity change being prepared in a popup,
+/// starting from the first target's current values.
+#[derive(Debug, Clone)]
+pub struct ProcessTuning {
+    /// The selected process, or every tagged one
+    pub targets: Vec<(ProcessKey, String)>,
+    pub setting: Tuning,
+}
+
+#[derive(Debug, Clone)]
+pub enum Tuning {
+    Nice { current: i64, new: i64 },
+    IoPriority { current: IoPriority, new: IoPriority },
+    /// One flag per CPU; `cursor` is the CPU being edited
+    Affinity { current: Vec<bool>, new: Vec<bool>, cursor: usize },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -102,6 +220,8 @@ pub enum Focus {
 pub enum Mode {
     Normal,
     Command,
+    /// Typing an incremental Processes filter after `/`
+    Filter,
 }
 
 pub struct App {
@@ -118,10 +238,50 @@ pub struct App {
     pub command_error: Option<String>,
     pub show_device_popup: bool,
     pub selected_device_index: Option<usize>,
-    pub show_kill_confirm: bool,
-    pub kill_target_pid: Option<u32>,
-    pub kill_target_name: Option<String>,
+    pub show_network_popup: bool,
+    pub selected_network_index: Option<usize>,
+    pub show_signal_menu: bool,
+    /// Index into `signal::MENU`; one past the end is "TERM, then KILL"
+    pub signal_menu_index: usize,
+    pub kill_grace: Duration,
+    /// The process selected in the Processes tab, which the selection
+    /// follows across refreshes
+    pub selected_process: Option<ProcessKey>,
+    /// Thread selected under an expanded process, if a thread row is selected
+    pub selected_thread: Option<u32>,
+    /// Processes the signal menu will signal, in order: the selected one,
+    /// every tagged one, or a subtree with the descendants first
+    pub kill_targets: Vec<(ProcessKey, String)>,
+    /// Whether `kill_targets` is the subtree of its last entry
+    pub kill_subtree: bool,
+    pub process_tuning: Option<ProcessTuning>,
+    /// Processes tagged with Space for batch actions
+    pub tagged_processes: HashSet<ProcessKey>,
+    pub show_export_confirm: bool,
+    pub export_path: Option<PathBuf>,
     pub status_message: Option<String>,
+    pub process_columns: HashSet<ProcessColumn>,
+    pub show_column_chooser: bool,
+    pub column_chooser_index: usize,
+    pub process_filter: String,
+    pub process_tree: bool,
+    pub collapsed_pids: HashSet<u32>,
+    /// Processes whose threads are listed under them
+    pub expanded_threads: HashMap<ProcessKey, ProcessThreads>,
+    pub connections_listening_only: bool,
+    pub connections_pid_filter: Option<u32>,
+    pub disk_full_horizon: Duration,
+    pub disk_explorer: Option<DiskExplorer>,
+    pub process_detail: Option<ProcessDetail>,
+    pub show_delete_confirm: bool,
+    pub delete_target: Option<PathBuf>,
+    next_scan_id: u64,
+    event_tx: Option<mpsc::Sender<Event>>,
+    /// While paused, the data that keeps being collected; `system_data`
+    /// then holds the frozen snapshot on screen
+    live_data: Option<SystemData>,
+    /// Show the next refresh, then stay paused
+    step_pending: bool,
 }
 
 impl App {
@@ -140,15 +300,44 @@ impl App {
             command_error: None,
             show_device_popup: false,
             selected_device_index: None,
-            show_kill_confirm: false,
-            kill_target_pid: None,
-            kill_target_name: None,
+            show_network_popup: false,
+            selected_network_index: None,
+            show_signal_menu: false,
+            signal_menu_index: 0,
+            kill_grace: DEFAULT_KILL_GRACE,
+            selected_process: None,
+            selected_thread: None,
+            kill_targets: Vec::new(),
+            kill_subtree: false,
+            process_tuning: None,
+            tagged_processes: HashSet::new(),
+            show_export_confirm: false,
+            export_path: None,
             status_message: None,
+            process_columns: ProcessColumn::all().into_iter().filter(|c| c.is_default()).collect(),
+            show_column_chooser: false,
+            column_chooser_index: 0,
+            process_filter: String::new(),
+            process_tree: false,
+            collapsed_pids: HashSet::new(),
+            expanded_threads: HashMap::new(),
+            connections_listening_only: false,
+            connections_pid_filter: None,
+            disk_full_horizon: DEFAULT_DISK_FULL_HORIZON,
+            disk_explorer: None,
+            process_detail: None,
+            show_delete_confirm: false,
+            delete_target: None,
+            next_scan_id: 0,
+            live_data: None,
+            step_pending: false,
+            event_tx: None,
         }
     }
 
     pub async fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> Result<()> {
         let (tx, mut rx) = mpsc::channel::<Event>(100);
+        self.event_tx = Some(tx.clone());
 
         // Spawn system data refresh task
         let tx_refresh = tx.clone();
@@ -177,7 +366,7 @@ impl App {
         });
 
         // Initial data load
-        self.system_data.refresh();
+        self.refresh_data();
 
         while self.running {
             terminal.draw(|f| ui::draw(f, self))?;
@@ -185,7 +374,23 @@ impl App {
             if let Some(event) = rx.recv().await {
                 match event {
                     Event::Input(evt) => self.handle_input(evt),
-                    Event::Refresh => self.system_data.refresh(),
+                    Event::Refresh => self.refresh_data(),
+                    Event::ScanProgress(id, count) => {
+                        if let Some(explorer) = self.disk_explorer.as_mut() {
+                            if explorer.scan_id == id {
+                                explorer.scanned = count;
+                            }
+                        }
+                    }
+                    Event::ScanDone(id, root) => {
+                        if let Some(explorer) = self.disk_explorer.as_mut() {
+                            if explorer.scan_id == id {
+                                explorer.root = Some(root);
+                            }
+                        }
+                    }
+                    Event::DeleteDone(id, path, result) => self.finish_delete(id, path, result),
+                    Event::KillGraceExpired(pids) => self.escalate_kill(&pids),
                 }
             }
         }
@@ -199,11 +404,24 @@ impl App {
                 return;
             }
 
+            let shown = self.wanted_collections();
+
             // Handle based on current mode
             match self.mode {
                 Mode::Command => self.handle_command_input(key.code),
+                Mode::Filter => self.handle_filter_input(key.code),
                 Mode::Normal => self.handle_normal_input(key.code),
             }
+
+            if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) {
+                self.pin_process_selection();
+            }
+
+            // Fill a newly shown tab now rather than at the next refresh
+            let wanted = self.wanted_collections();
+            if wanted != shown && !self.is_paused() {
+                self.system_data.refresh_collections(wanted);
+            }
         }
     }
 
@@ -231,9 +449,32 @@ impl App {
         }
     }
 
+    fn handle_filter_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                self.process_filter.clear();
+            }
+            KeyCode::Enter => {
+                self.mode = Mode::Normal;
+                return;
+            }
+            KeyCode::Backspace => {
+                self.process_filter.pop();
+            }
+            KeyCode::Char(c) => {
+                self.process_filter.push(c);
+            }
+            _ => return,
+        }
+        // Results change with every keystroke, so start from the top
+        self.reset_selection(TreeNode::Processes, 0);
+    }
+
     fn execute_command(&mut self) {
-        let cmd = self.command_buffer.trim();
-        match cmd {
+        let cmd = self.command_buffer.trim().to_string();
+        let (name, arg) = cmd.split_once(' ').unwrap_or((cmd.as_str(), ""));
+        match name {
             "q" | "quit" => {
                 self.running = false;
             }
@@ -245,6 +486,51 @@ impl App {
                 self.mode = Mode::Normal;
                 self.command_buffer.clear();
             }
+            "export" => {
+                // Writes the tagged processes, or everything shown if none are
+                let arg = arg.trim();
+                let path = if arg.is_empty() {
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+                    PathBuf::from(format!("processes-{}.csv", now))
+                } else {
+                    PathBuf::from(arg)
+                };
+                self.export_path = Some(path);
+                self.show_export_confirm = true;
+            }
+            "grace" => {
+                // A bare number means seconds here, unlike the horizon
+                let arg = arg.trim();
+                let grace = arg
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|secs| *secs > 0)
+                    .map(Duration::from_secs)
+                    .or_else(|| parse_duration(arg));
+                if arg.is_empty() {
+                    self.status_message = Some(format!("TERM grace period: {}", format_uptime(self.kill_grace.as_secs())));
+                } else if let Some(grace) = grace {
+                    self.kill_grace = grace;
+                    self.status_message = Some(format!("TERM grace period set to {}", format_uptime(grace.as_secs())));
+                } else {
+                    self.command_error = Some(format!("Invalid duration: {} (e.g. 10, 30s, 2m)", arg));
+                }
+            }
+            "pause" => self.toggle_pause(),
+            "horizon" => {
+                let arg = arg.trim();
+                if arg.is_empty() {
+                    self.status_message = Some(format!(
+                        "Disk-full horizon: {}",
+                        format_uptime(self.disk_full_horizon.as_secs())
+                    ));
+                } else if let Some(horizon) = parse_duration(arg) {
+                    self.disk_full_horizon = horizon;
+                    self.status_message = Some(format!("Disk-full horizon set to {}", format_uptime(horizon.as_secs())));
+                } else {
+                    self.command_error = Some(format!("Invalid duration: {} (e.g. 30m, 6h, 2d)", arg));
+                }
+            }
             _ => {
                 self.command_error = Some(format!("Unknown command: {}", cmd));
             }
@@ -259,23 +545,81 @@ impl App {
         // Clear status message on any key
         self.status_message = None;
 
-        // Handle kill confirmation popup
-        if self.show_kill_confirm {
+        // Handle the signal menu: pick a signal with j/k, send it with Enter
+        if self.show_signal_menu {
+            match key {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.signal_menu_index = self.signal_menu_index.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.signal_menu_index = (self.signal_menu_index + 1).min(signal::MENU.len());
+                }
+                KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => self.execute_kill(),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Char('q') | KeyCode::Esc => {
+                    self.show_signal_menu = false;
+                    self.kill_targets.clear();
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Handle export confirmation popup
+        if self.show_export_confirm {
             match key {
                 KeyCode::Char('y') | KeyCode::Char('Y') => {
-                    self.execute_kill();
+                    self.execute_export();
                     return;
                 }
                 KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                    self.show_kill_confirm = false;
-                    self.kill_target_pid = None;
-                    self.kill_target_name = None;
+                    self.show_export_confirm = false;
+                    self.export_path = None;
                     return;
                 }
                 _ => return,
             }
         }
 
+        // Handle delete confirmation popup
+        if self.show_delete_confirm {
+            match key {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.execute_delete();
+                    return;
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    self.show_delete_confirm = false;
+                    self.delete_target = None;
+                    return;
+                }
+                _ => return,
+            }
+        }
+
+        // Renice / ionice / affinity popup
+        if self.process_tuning.is_some() {
+            self.handle_tuning_input(key);
+            return;
+        }
+
+        // Column chooser: move with j/k, toggle with Space or Enter
+        if self.show_column_chooser {
+            match key {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.column_chooser_index = self.column_chooser_index.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.column_chooser_index = (self.column_chooser_index + 1).min(ProcessColumn::all().len() - 1);
+                }
+                KeyCode::Char(' ') | KeyCode::Enter => self.toggle_process_column(),
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(2) => {
+                    self.show_column_chooser = false;
+                }
+                _ => {}
+            }
+            return;
+        }
+
         // Close device popup if open
         if self.show_device_popup {
             match key {
@@ -288,6 +632,18 @@ impl App {
             }
         }
 
+        // Close network popup if open
+        if self.show_network_popup {
+            match key {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    self.show_network_popup = false;
+                    self.selected_network_index = None;
+                    return;
+                }
+                _ => return,
+            }
+        }
+
         // Global keys
         match key {
             KeyCode::Char(':') => {
@@ -318,6 +674,19 @@ impl App {
             return;
         }
 
+        match key {
+            KeyCode::Char('z') => {
+                self.toggle_pause();
+                return;
+            }
+            KeyCode::Char('.') if self.is_paused() => {
+                self.step_pending = true;
+                self.status_message = Some("Showing the next refresh".to_string());
+                return;
+            }
+            _ => {}
+        }
+
         // Handle shortcut keys to open tabs directly (works from any pane)
         if let KeyCode::Char(c) = key {
             if let Some(node) = TreeNode::from_shortcut(c) {
@@ -370,20 +739,18 @@ impl App {
                     self.active_tab_index += 1;
                 }
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
-                    if tab.selected_item > 0 {
-                        tab.selected_item -= 1;
-                    }
-                    if tab.selected_item < tab.scroll_offset as usize {
-                        tab.scroll_offset = tab.selected_item as u16;
-                    }
-                }
-            }
-            KeyCode::Down | KeyCode::Char('j') => {
+            KeyCode::Up
+            | KeyCode::Char('k')
+            | KeyCode::Down
+            | KeyCode::Char('j')
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::Char('g')
+            | KeyCode::Char('G') => {
                 if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
-                    tab.selected_item += 1;
-                    tab.scroll_offset = tab.scroll_offset.saturating_add(0);
+                    tab.navigate(key);
                 }
             }
             KeyCode::Enter => {
@@ -395,23 +762,213 @@ impl App {
                             self.selected_device_index = Some(tab.selected_item);
                             self.show_device_popup = true;
                         }
+                    } else if tab.node == TreeNode::Network {
+                        if tab.selected_item < self.system_data.network_list.len() {
+                            self.selected_network_index = Some(tab.selected_item);
+                            self.show_network_popup = true;
+                        }
+                    } else if tab.node == TreeNode::Connections {
+                        let pid = self
+                            .visible_connections()
+                            .get(tab.selected_item)
+                            .and_then(|c| c.pid);
+                        match pid {
+                            Some(pid) => self.jump_to_process(pid),
+                            None => {
+                                self.status_message = Some("Owning process unknown (try running as root)".to_string());
+                            }
+                        }
+                    } else if tab.node == TreeNode::Ipc {
+                        let pid = self
+                            .system_data
+                            .ipc
+                            .unix_sockets
+                            .get(tab.selected_item)
+                            .and_then(|s| s.pid);
+                        match pid {
+                            Some(pid) => self.jump_to_process(pid),
+                            None => {
+                                self.status_message = Some("Owning process unknown (try running as root)".to_string());
+                            }
+                        }
+                    } else if tab.node == TreeNode::Processes {
+                        let selected = tab.selected_item;
+                        if let Some(detail) = self.visible_processes().get(selected).map(|p| ProcessDetail::new(p)) {
+                            self.process_detail = Some(detail);
+                            self.open_tab_by_node(TreeNode::ProcessDetail);
+                            self.reset_selection(TreeNode::ProcessDetail, 0);
+                        }
+                    } else if tab.node == TreeNode::Disks {
+                        if let Some(disk) = self.system_data.disk_list.get(tab.selected_item) {
+                            let mount = PathBuf::from(&disk.mount_point);
+                            self.start_disk_scan(mount);
+                        }
+                    } else if tab.node == TreeNode::DiskUsage {
+                        let selected = tab.selected_item;
+                        if let Some(explorer) = self.disk_explorer.as_mut() {
+                            if explorer.enter(selected) {
+                                self.reset_selection(TreeNode::DiskUsage, 0);
+                            }
+                        }
                     }
                 }
             }
+            KeyCode::Backspace
+                if self.active_tab().map(|t| t.node) == Some(TreeNode::DiskUsage) =>
+            {
+                // Go up one directory in the disk usage explorer
+                if let Some(idx) = self.disk_explorer.as_mut().and_then(|e| e.back()) {
+                    self.reset_selection(TreeNode::DiskUsage, idx);
+                }
+            }
             KeyCode::Char('x') | KeyCode::Char('X') | KeyCode::Delete => {
                 // Kill process if in Processes tab
                 if let Some(tab) = self.active_tab() {
-                    if tab.node == TreeNode::Processes {
-                        let process_count = self.system_data.processes.len();
-                        if process_count > 0 && tab.selected_item < process_count {
-                            let proc = &self.system_data.processes[tab.selected_item];
-                            self.kill_target_pid = Some(proc.pid);
-                            self.kill_target_name = Some(proc.name.clone());
-                            self.show_kill_confirm = true;
+                    if tab.node == TreeNode::DiskUsage {
+                        let selected = tab.selected_item;
+                        let target = self
+                            .disk_explorer
+                            .as_ref()
+                            .and_then(|e| e.current())
+                            .and_then(|d| d.children.get(selected))
+                            .map(|c| c.path.clone());
+                        if let Some(path) = target {
+                            self.delete_target = Some(path);
+                            self.show_delete_confirm = true;
+                        }
+                    } else if tab.node == TreeNode::Processes {
+                        let targets = self.action_targets();
+                        if !targets.is_empty() {
+                            self.kill_targets = targets;
+                            self.kill_subtree = false;
+                            self.signal_menu_index = 0;
+                            self.show_signal_menu = true;
                         }
                     }
                 }
             }
+            KeyCode::Char('s') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                // Cycle through the visible columns only
+                let columns: Vec<ProcessColumn> = ProcessColumn::all()
+                    .into_iter()
+                    .filter(|c| self.process_columns.contains(c))
+                    .collect();
+                if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
+                    let current = columns.iter().position(|c| *c == tab.sort_column);
+                    let next = current.map(|i| (i + 1) % columns.len()).unwrap_or(0);
+                    tab.sort_column = columns[next];
+                    tab.sort_ascending = tab.sort_column.default_ascending();
+                }
+                self.sort_processes();
+            }
+            KeyCode::Char('t') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                self.process_tree = !self.process_tree;
+                self.status_message = Some(format!("Tree view {}", if self.process_tree { "on" } else { "off" }));
+                self.reset_selection(TreeNode::Processes, 0);
+            }
+            KeyCode::Char('-') | KeyCode::Char('+') | KeyCode::Char('=')
+                if self.process_tree && self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) =>
+            {
+                // Collapse or expand the subtree under the selected process
+                let selected = self.active_tab().map(|t| t.selected_item).unwrap_or(0);
+                let pid = self
+                    .process_rows()
+                    .get(selected)
+                    .filter(|row| row.has_children)
+                    .map(|row| row.proc.pid);
+                if let Some(pid) = pid {
+                    if key == KeyCode::Char('-') {
+                        self.collapsed_pids.insert(pid);
+                    } else {
+                        self.collapsed_pids.remove(&pid);
+                    }
+                }
+            }
+            KeyCode::Char('K') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                // Kill the selected process together with everything it spawned
+                let selected = self.active_tab().map(|t| t.selected_item).unwrap_or(0);
+                let target = self
+                    .visible_processes()
+                    .get(selected)
+                    .map(|p| (p.key(), p.name.clone()));
+                if let Some(target) = target {
+                    let descendants = proctree::descendants(&self.system_data.processes, target.0.pid);
+                    self.kill_targets = descendants
+                        .into_iter()
+                        .map(|key| (key, self.process_name(key)))
+                        .chain(std::iter::once(target))
+                        .collect();
+                    self.kill_subtree = true;
+                    self.signal_menu_index = 0;
+                    self.show_signal_menu = true;
+                }
+            }
+            KeyCode::Char('e') | KeyCode::Char('i') | KeyCode::Char('a')
+                if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) =>
+            {
+                self.open_process_tuning(key);
+            }
+            KeyCode::Char(' ') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                // Tag or untag the selected process and move on to the next
+                if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
+                    let selected = tab.selected_item;
+                    tab.select(selected + 1);
+                    if let Some(key) = self.visible_processes().get(selected).map(|p| p.key()) {
+                        if !self.tagged_processes.remove(&key) {
+                            self.tagged_processes.insert(key);
+                        }
+                    }
+                }
+            }
+            KeyCode::Char('H') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                // Show or hide the threads of the selected process
+                let selected = self.active_tab().map(|t| t.selected_item).unwrap_or(0);
+                if let Some(key) = self.visible_processes().get(selected).map(|p| p.key()) {
+                    if self.expanded_threads.remove(&key).is_none() {
+                        let mut threads = ProcessThreads::default();
+                        threads.refresh(key.pid);
+                        self.expanded_threads.insert(key, threads);
+                    }
+                }
+            }
+            KeyCode::Char('T') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                // Tag everything the filter shows, or clear the tags if that's
+                // already done
+                let keys: Vec<ProcessKey> = self.visible_processes().iter().map(|p| p.key()).collect();
+                if keys.iter().all(|key| self.tagged_processes.contains(key)) {
+                    self.tagged_processes.clear();
+                    self.status_message = Some("Cleared all tags".to_string());
+                } else {
+                    self.tagged_processes.extend(keys);
+                    self.status_message = Some(format!("{} processes tagged", self.tagged_processes.len()));
+                }
+            }
+            KeyCode::F(2) if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                self.show_column_chooser = true;
+            }
+            KeyCode::Char('/') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                self.mode = Mode::Filter;
+            }
+            KeyCode::Char('S') if self.active_tab().map(|t| t.node) == Some(TreeNode::Processes) => {
+                if let Some(tab) = self.tabs.get_mut(self.active_tab_index) {
+                    tab.sort_ascending = !tab.sort_ascending;
+                }
+                self.sort_processes();
+            }
+            KeyCode::Char('s') if self.active_tab().map(|t| t.node) == Some(TreeNode::Connections) => {
+                self.connections_listening_only = !self.connections_listening_only;
+                self.reset_selection(TreeNode::Connections, 0);
+            }
+            KeyCode::Char('i') if self.active_tab().map(|t| t.node) == Some(TreeNode::Connections) => {
+                // Toggle showing only the selected connection's process
+                if self.connections_pid_filter.is_some() {
+                    self.connections_pid_filter = None;
+                } else {
+                    let selected = self.active_tab().map(|t| t.selected_item).unwrap_or(0);
+                    self.connections_pid_filter = self.visible_connections().get(selected).and_then(|c| c.pid);
+                }
+                self.reset_selection(TreeNode::Connections, 0);
+            }
             KeyCode::Char('w') => {
                 self.close_current_tab();
             }
@@ -438,6 +995,11 @@ impl App {
             // Create new tab
             self.tabs.push(Tab::new(node));
             self.active_tab_index = self.tabs.len() - 1;
+            // A new Processes tab starts from the default sort
+            if node == TreeNode::Processes {
+                let (column, ascending) = self.process_sort();
+                self.system_data.sort_processes(column, ascending);
+            }
         }
 
         // Update tree selection to match
@@ -458,38 +1020,698 @@ impl App {
     }
 
     fn execute_kill(&mut self) {
-        if let Some(pid) = self.kill_target_pid {
-            let name = self.kill_target_name.clone().unwrap_or_default();
-
-            // Try to kill the process using kill command
-            let result = std::process::Command::new("kill")
-                .arg("-9")
-                .arg(pid.to_string())
-                .output();
-
-            match result {
-                Ok(output) => {
-                    if output.status.success() {
-                        self.status_message = Some(format!("Killed process {} (PID: {})", name, pid));
-                        // Refresh process list
-                        self.system_data.refresh();
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        self.status_message = Some(format!("Failed to kill {}: {}", name, stderr.trim()));
+        let targets = std::mem::take(&mut self.kill_targets);
+        let escalate = self.signal_menu_index >= signal::MENU.len();
+        let sig = signal::MENU.get(self.signal_menu_index).copied().unwrap_or(signal::TERM);
+
+        // Signal in order, so a subtree's children go before their parent.
+        // Each PID is re-checked first: the list may be a refresh old, and a
+        // PID reused since then must not be signalled
+        let mut sent = Vec::new();
+        let mut failures = Vec::new();
+        for (key, name) in &targets {
+            match key.verify().and_then(|()| signal::send(key.pid, sig)) {
+                Ok(()) => sent.push(*key),
+                Err(e) => failures.push((key.pid, name, e)),
+            }
+        }
+
+        if let Some((key, name)) = targets.last() {
+            let description = if targets.len() == 1 {
+                format!("{} (PID: {})", name, key.pid)
+            } else if self.kill_subtree {
+                format!("{} (PID: {}) and {} descendants", name, key.pid, targets.len() - 1)
+            } else {
+                format!("{} processes", targets.len())
+            };
+
+            self.status_message = Some(match failures.first() {
+                None => format!("Sent {} to {}", sig.name, description),
+                Some((_, _, error)) if targets.len() == 1 => {
+                    format!("Failed to send {} to {}: {}", sig.name, description, error)
+                }
+                Some((pid, name, error)) => format!(
+                    "Sent {} to {} of {}; {} failed, e.g. {} (PID: {}): {}",
+                    sig.name,
+                    sent.len(),
+                    description,
+                    failures.len(),
+                    name,
+                    pid,
+                    error
+                ),
+            });
+
+            // The next scheduled refresh drops whatever exited
+            if escalate && !sent.is_empty() {
+                self.schedule_kill(sent);
+            }
+        }
+
+        self.show_signal_menu = false;
+    }
+
+    /// The processes a batch action applies to: every tagged one if any are
+    /// tagged, otherwise the selected one.
+    fn action_targets(&self) -> Vec<(ProcessKey, String)> {
+        if self.tagged_processes.is_empty() {
+            let selected = self.active_tab().map(|t| t.selected_item).unwrap_or(0);
+            return self
+                .visible_processes()
+                .get(selected)
+                .map(|p| vec![(p.key(), p.name.clone())])
+                .unwrap_or_default();
+        }
+
+        self.system_data
+            .processes
+            .iter()
+            .filter(|p| self.tagged_processes.contains(&p.key()))
+            .map(|p| (p.key(), p.name.clone()))
+            .collect()
+    }
+
+    fn process_name(&self, key: ProcessKey) -> String {
+        self.system_data
+            .processes
+            .iter()
+            .find(|p| p.key() == key)
+            .map(|p| p.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Opens the renice (`e`), ionice (`i`) or affinity (`a`) popup for the
+    /// selected process, filled in with its current setting.
+    fn open_process_tuning(&mut self, key: KeyCode) {
+        let targets = self.action_targets();
+        let Some((target, name)) = targets.first().cloned() else {
+            return;
+        };
+
+        let setting = match key {
+            KeyCode::Char('e') => sched::nice(target.pid).map(|nice| Tuning::Nice { current: nice, new: nice }),
+            KeyCode::Char('i') => sched::io_priority(target.pid).map(|prio| Tuning::IoPriority { current: prio, new: prio }),
+            _ => sched::affinity(target.pid).map(|cpus| Tuning::Affinity {
+                current: cpus.clone(),
+                new: cpus,
+                cursor: 0,
+            }),
+        };
+
+        match setting {
+            Ok(setting) => self.process_tuning = Some(ProcessTuning { targets, setting }),
+            Err(e) => self.status_message = Some(format!("Cannot read settings of {} (PID: {}): {}", name, target.pid, e)),
+        }
+    }
+
+    fn handle_tuning_input(&mut self, key: KeyCode) {
+        let Some(tuning) = self.process_tuning.as_mut() else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.process_tuning = None;
+                return;
+            }
+            KeyCode::Enter => {
+                self.apply_process_tuning();
+                return;
+            }
+            _ => {}
+        }
+
+        match &mut tuning.setting {
+            Tuning::Nice { new, .. } => match key {
+                KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('-') => *new = (*new - 1).max(-20),
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('+') => *new = (*new + 1).min(19),
+                _ => {}
+            },
+            Tuning::IoPriority { new, .. } => {
+                let classes = IoClass::all();
+                let idx = classes.iter().position(|c| *c == new.class).unwrap_or(0);
+                match key {
+                    KeyCode::Up | KeyCode::Char('k') => new.class = classes[idx.saturating_sub(1)],
+                    KeyCode::Down | KeyCode::Char('j') => new.class = classes[(idx + 1).min(classes.len() - 1)],
+                    KeyCode::Left | KeyCode::Char('h') => new.level = new.level.saturating_sub(1),
+                    KeyCode::Right | KeyCode::Char('l') => new.level = (new.level + 1).min(7),
+                    _ => {}
+                }
+            }
+            Tuning::Affinity { new, cursor, .. } => match key {
+                KeyCode::Up | KeyCode::Left | KeyCode::Char('k') | KeyCode::Char('h') => {
+                    *cursor = cursor.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Right | KeyCode::Char('j') | KeyCode::Char('l') => {
+                    *cursor = (*cursor + 1).min(new.len().saturating_sub(1));
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(cpu) = new.get_mut(*cursor) {
+                        *cpu = !*cpu;
                     }
                 }
-                Err(e) => {
-                    self.status_message = Some(format!("Error killing {}: {}", name, e));
+                KeyCode::Char('a') => {
+                    // Select all CPUs, or only the one under the cursor if all already are
+                    let all = new.iter().all(|cpu| *cpu);
+                    for (i, cpu) in new.iter_mut().enumerate() {
+                        *cpu = !all || i == *cursor;
+                    }
                 }
+                _ => {}
+            },
+        }
+    }
+
+    fn apply_process_tuning(&mut self) {
+        let Some(tuning) = self.process_tuning.take() else {
+            return;
+        };
+
+        let description = match &tuning.setting {
+            Tuning::Nice { new, .. } => format!("nice to {}", new),
+            Tuning::IoPriority { new, .. } => format!("I/O priority to {}", new.describe()),
+            Tuning::Affinity { new, .. } => format!("CPU affinity to {}", sched::format_cpu_list(new)),
+        };
+
+        let mut failures = Vec::new();
+        for (key, name) in &tuning.targets {
+            let result = key.verify().and_then(|()| match &tuning.setting {
+                Tuning::Nice { new, .. } => sched::set_nice(key.pid, *new as i32),
+                Tuning::IoPriority { new, .. } => sched::set_io_priority(key.pid, *new),
+                Tuning::Affinity { new, .. } => sched::set_affinity(key.pid, new),
+            });
+            if let Err(e) = result {
+                failures.push((key.pid, name, e));
+            }
+        }
+
+        let target = match tuning.targets.as_slice() {
+            [(key, name)] => format!("{} (PID: {})", name, key.pid),
+            targets => format!("{} processes", targets.len()),
+        };
+        self.status_message = Some(match failures.first() {
+            None => format!("Set {} of {}", description, target),
+            Some((_, _, e)) if tuning.targets.len() == 1 => format!("Failed to set {} of {}: {}", description, target, e),
+            Some((pid, name, e)) => format!(
+                "Set {} of {} of {}; {} failed, e.g. {} (PID: {}): {}",
+                description,
+                tuning.targets.len() - failures.len(),
+                target,
+                failures.len(),
+                name,
+                pid,
+                e
+            ),
+        });
+    }
+
+    /// Comes back after the grace period to KILL whatever ignored the TERM.
+    fn schedule_kill(&mut self, targets: Vec<ProcessKey>) {
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let grace = self.kill_grace;
+        tokio::spawn(async move {
+            tokio::time::sleep(grace).await;
+            let _ = tx.send(Event::KillGraceExpired(targets)).await;
+        });
+    }
+
+    fn escalate_kill(&mut self, targets: &[ProcessKey]) {
+        // Whatever exited (or whose PID was since reused) is left alone
+        let survivors: Vec<&ProcessKey> = targets.iter().filter(|key| key.verify().is_ok()).collect();
+        if survivors.is_empty() {
+            return;
+        }
+
+        let errors: Vec<String> = survivors
+            .iter()
+            .filter_map(|key| {
+                signal::send(key.pid, signal::KILL)
+                    .err()
+                    .map(|e| format!("PID {}: {}", key.pid, e))
+            })
+            .collect();
+        self.status_message = Some(match errors.first() {
+            None => format!("Sent KILL to {} process(es) still alive after TERM", survivors.len()),
+            Some(error) => format!("Failed to KILL after TERM, {}", error),
+        });
+    }
+
+    fn refresh_data(&mut self) {
+        let wanted = self.wanted_collections();
+        if let Some(live) = self.live_data.as_mut() {
+            // Keep collecting so rates and histories have no gap on resume
+            live.refresh(wanted);
+            if !self.step_pending {
+                return;
+            }
+            self.step_pending = false;
+            self.system_data = live.snapshot();
+        } else {
+            self.system_data.refresh(wanted);
+        }
+        self.show_refreshed_data();
+    }
+
+    /// Collections needed by the tab on screen; the others are skipped.
+    fn wanted_collections(&self) -> Collections {
+        let node = self.active_tab().map(|t| t.node);
+        Collections {
+            net_config: node == Some(TreeNode::Routing),
+            firewall: node == Some(TreeNode::Firewall),
+            sockets: matches!(node, Some(TreeNode::Connections | TreeNode::Ipc)),
+            wireless: node == Some(TreeNode::Network),
+        }
+    }
+
+    /// Brings everything derived from `system_data` up to date after it
+    /// was refreshed or replaced.
+    fn show_refreshed_data(&mut self) {
+        let (column, ascending) = self.process_sort();
+        self.system_data.sort_processes(column, ascending);
+        self.restore_process_selection();
+
+        // Forget tags and expanded threads of processes that have exited
+        let alive: HashSet<ProcessKey> = self.system_data.processes.iter().map(|p| p.key()).collect();
+        self.tagged_processes.retain(|key| alive.contains(key));
+        self.expanded_threads.retain(|key, _| alive.contains(key));
+        for (key, threads) in self.expanded_threads.iter_mut() {
+            threads.refresh(key.pid);
+        }
+
+        // Only keep re-reading /proc for the detail view while it is open
+        if self.tabs.iter().any(|t| t.node == TreeNode::ProcessDetail) {
+            if let Some(detail) = self.process_detail.as_mut() {
+                detail.refresh(&self.system_data.processes);
+            }
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.live_data.is_some()
+    }
+
+    /// Freezes the data on screen while collection carries on, or goes back
+    /// to live data.
+    fn toggle_pause(&mut self) {
+        match self.live_data.take() {
+            Some(live) => {
+                self.system_data = live;
+                self.step_pending = false;
+                self.show_refreshed_data();
+                self.status_message = Some("Resumed".to_string());
+            }
+            None => {
+                let snapshot = self.system_data.snapshot();
+                self.live_data = Some(std::mem::replace(&mut self.system_data, snapshot));
+                self.status_message = Some("Paused: z or :pause to resume, . to step one refresh".to_string());
+            }
+        }
+    }
+
+    /// Sort column and direction of the Processes tab, or the defaults
+    /// while it is closed.
+    pub fn process_sort(&self) -> (ProcessColumn, bool) {
+        self.tabs
+            .iter()
+            .find(|t| t.node == TreeNode::Processes)
+            .map(|t| (t.sort_column, t.sort_ascending))
+            .unwrap_or((ProcessColumn::Cpu, false))
+    }
+
+    fn sort_processes(&mut self) {
+        let (column, ascending) = self.process_sort();
+        self.system_data.sort_processes(column, ascending);
+        self.restore_process_selection();
+        self.status_message = Some(format!(
+            "Sorted by {} {}",
+            column.name(),
+            if ascending { "ascending" } else { "descending" }
+        ));
+    }
+
+    /// Shows or hides the column under the chooser cursor, keeping at least
+    /// one column visible.
+    fn toggle_process_column(&mut self) {
+        let Some(column) = ProcessColumn::all().get(self.column_chooser_index).copied() else {
+            return;
+        };
+
+        if !self.process_columns.contains(&column) {
+            self.process_columns.insert(column);
+        } else if self.process_columns.len() > 1 {
+            self.process_columns.remove(&column);
+        } else {
+            self.status_message = Some("At least one column must stay visible".to_string());
+        }
+    }
+
+    /// TCP/UDP sockets after applying the Connections view filters.
+    pub fn visible_connections(&self) -> Vec<&Connection> {
+        self.system_data
+            .connections
+            .iter()
+            .filter(|c| !self.connections_listening_only || c.is_listening())
+            .filter(|c| self.connections_pid_filter.is_none() || c.pid == self.connections_pid_filter)
+            .collect()
+    }
+
+    /// Rows of the Processes table after applying the `/` filter, in sort
+    /// order or arranged as a tree.
+    pub fn process_rows(&self) -> Vec<ProcessRow<'_>> {
+        let filter = ProcessFilter::parse(&self.process_filter);
+        let matching: Vec<&ProcessInfo> = self
+            .system_data
+            .processes
+            .iter()
+            .filter(|p| filter.matches(p))
+            .collect();
+
+        let rows = if self.process_tree {
+            proctree::build(&matching, &self.collapsed_pids)
+        } else {
+            proctree::flat(&matching)
+        };
+        if self.expanded_threads.is_empty() {
+            return rows;
+        }
+
+        // List expanded processes' threads right below them
+        let mut with_threads = Vec::with_capacity(rows.len());
+        for row in rows {
+            let threads = self.expanded_threads.get(&row.proc.key());
+            let depth = row.depth + 1;
+            with_threads.push(row.clone());
+            for thread in threads.into_iter().flat_map(|t| &t.threads) {
+                with_threads.push(ProcessRow {
+                    thread: Some(thread),
+                    depth,
+                    has_children: false,
+                    collapsed: false,
+                    ..row.clone()
+                });
             }
         }
+        with_threads
+    }
+
+    /// Processes in the order they appear in the Processes table.
+    pub fn visible_processes(&self) -> Vec<&ProcessInfo> {
+        self.process_rows().into_iter().map(|row| row.proc).collect()
+    }
+
+    fn jump_to_process(&mut self, pid: u32) {
+        if !self.system_data.processes.iter().any(|p| p.pid == pid) {
+            self.status_message = Some(format!("Process {} not found", pid));
+            return;
+        }
+        // Make sure the filter or a collapsed subtree doesn't hide the
+        // process we're jumping to
+        if !self.visible_processes().iter().any(|p| p.pid == pid) {
+            self.process_filter.clear();
+        }
+        if !self.visible_processes().iter().any(|p| p.pid == pid) {
+            self.collapsed_pids.clear();
+        }
+        let processes = self.visible_processes();
+        let idx = processes.iter().position(|p| p.pid == pid).unwrap_or(0);
+        let len = processes.len();
+        self.open_tab_by_node(TreeNode::Processes);
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.node == TreeNode::Processes) {
+            tab.selected_item = idx;
+            tab.scroll_offset = tab.visible_offset(len, tab.page_height.get());
+        }
+    }
+
+    /// Remembers which process is selected in the Processes tab, so the
+    /// selection can follow it when the list is refreshed or re-sorted.
+    fn pin_process_selection(&mut self) {
+        let Some(selected) = self.tabs.iter().find(|t| t.node == TreeNode::Processes).map(|t| t.selected_item) else {
+            return;
+        };
+        if let Some((key, tid)) = self.process_rows().get(selected).map(|row| (row.proc.key(), row.thread.map(|t| t.tid))) {
+            self.selected_process = Some(key);
+            self.selected_thread = tid;
+        }
+    }
+
+    /// Moves the Processes selection to the row of the pinned process. If it
+    /// has exited, the selection stays on the same row.
+    fn restore_process_selection(&mut self) {
+        let Some(key) = self.selected_process else {
+            return;
+        };
+        let rows = self.process_rows();
+        let matches = |row: &ProcessRow| row.proc.key() == key && row.thread.map(|t| t.tid) == self.selected_thread;
+        // Fall back to the process row if the selected thread has exited
+        let idx = rows
+            .iter()
+            .position(matches)
+            .or_else(|| rows.iter().position(|row| row.proc.key() == key && row.thread.is_none()));
+        let len = rows.len();
+
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.node == TreeNode::Processes) {
+            tab.selected_item = idx.unwrap_or(tab.selected_item.min(len.saturating_sub(1)));
+            tab.scroll_offset = tab.visible_offset(len, tab.page_height.get());
+        }
+        if idx.is_none() {
+            self.pin_process_selection();
+        }
+    }
+
+    fn start_disk_scan(&mut self, root: PathBuf) {
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+
+        self.next_scan_id += 1;
+        let id = self.next_scan_id;
+        self.disk_explorer = Some(DiskExplorer::new(id, root.clone()));
+
+        // Scan on a blocking thread; results come back through the event loop
+        tokio::task::spawn_blocking(move || {
+            let mut progress = |count| {
+                let _ = tx.try_send(Event::ScanProgress(id, count));
+            };
+            let tree = diskusage::scan(&root, &mut progress);
+            let _ = tx.blocking_send(Event::ScanDone(id, tree));
+        });
+
+        self.open_tab_by_node(TreeNode::DiskUsage);
+        self.reset_selection(TreeNode::DiskUsage, 0);
+    }
+
+    fn reset_selection(&mut self, node: TreeNode, selected: usize) {
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.node == node) {
+            tab.selected_item = selected;
+            tab.scroll_offset = 0;
+        }
+    }
 
-        self.show_kill_confirm = false;
-        self.kill_target_pid = None;
-        self.kill_target_name = None;
+    /// Processes `:export` writes: the tagged ones, or else every process
+    /// the Processes table currently shows.
+    pub fn export_targets(&self) -> Vec<&ProcessInfo> {
+        if self.tagged_processes.is_empty() {
+            self.visible_processes()
+        } else {
+            self.system_data
+                .processes
+                .iter()
+                .filter(|p| self.tagged_processes.contains(&p.key()))
+                .collect()
+        }
+    }
+
+    fn execute_export(&mut self) {
+        self.show_export_confirm = false;
+        let Some(path) = self.export_path.take() else {
+            return;
+        };
+
+        let mut csv = String::from("pid,ppid,user,name,state,cpu_percent,memory_bytes,virtual_bytes,threads,nice,start_time,command\n");
+        let targets = self.export_targets();
+        for proc in &targets {
+            let fields = [
+                proc.pid.to_string(),
+                proc.parent_pid.map(|p| p.to_string()).unwrap_or_default(),
+                proc.user.clone(),
+                proc.name.clone(),
+                proc.status.clone(),
+                format!("{:.1}", proc.cpu_usage),
+                proc.memory.to_string(),
+                proc.virtual_memory.to_string(),
+                proc.threads.to_string(),
+                proc.nice.to_string(),
+                proc.start_time.to_string(),
+                proc.cmdline.clone(),
+            ];
+            let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        let count = targets.len();
+
+        self.status_message = Some(match std::fs::write(&path, csv) {
+            Ok(()) => format!("Exported {} processes to {}", count, path.display()),
+            Err(e) => format!("Failed to export to {}: {}", path.display(), e),
+        });
+    }
+
+    fn execute_delete(&mut self) {
+        self.show_delete_confirm = false;
+        let Some(path) = self.delete_target.take() else {
+            return;
+        };
+        let (Some(tx), Some(explorer)) = (self.event_tx.clone(), self.disk_explorer.as_ref()) else {
+            return;
+        };
+        let id = explorer.scan_id;
+
+        // Large trees take a while to delete, so don't hold up the event loop
+        self.status_message = Some(format!("Deleting {}...", path.display()));
+        tokio::task::spawn_blocking(move || {
+            let result = match std::fs::symlink_metadata(&path) {
+                Ok(meta) if meta.is_dir() => std::fs::remove_dir_all(&path),
+                Ok(_) => std::fs::remove_file(&path),
+                Err(e) => Err(e),
+            };
+            let _ = tx.blocking_send(Event::DeleteDone(id, path, result.map_err(|e| e.to_string())));
+        });
+    }
+
+    fn finish_delete(&mut self, id: u64, path: PathBuf, result: Result<(), String>) {
+        match result {
+            Ok(()) => {
+                if let Some(explorer) = self.disk_explorer.as_mut().filter(|e| e.scan_id == id) {
+                    explorer.remove(&path);
+                }
+                self.status_message = Some(format!("Deleted {}", path.display()));
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to delete {}: {}", path.display(), e));
+            }
+        }
     }
 
     pub fn active_tab(&self) -> Option<&Tab> {
         self.tabs.get(self.active_tab_index)
     }
 }
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parses durations like `90s`, `30m`, `6h` or `2d`; bare numbers are hours.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let (num, unit) = match s.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => s.split_at(idx),
+        None => (s, "h"),
+    };
+    let value: u64 = num.parse().ok()?;
+    let secs = match unit {
+        "s" => Some(value),
+        "m" => value.checked_mul(60),
+        "h" => value.checked_mul(3600),
+        "d" => value.checked_mul(86400),
+        _ => return None,
+    }?;
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A tab whose list was last drawn with `len` rows in `height` rows.
+    fn drawn_tab(len: usize, height: usize) -> Tab {
+        let tab = Tab::new(TreeNode::Processes);
+        tab.list_len.set(len);
+        tab.page_height.set(height);
+        tab
+    }
+
+    #[test]
+    fn navigation_on_empty_list_stays_at_zero() {
+        let mut tab = drawn_tab(0, 10);
+        for key in [KeyCode::Down, KeyCode::End, KeyCode::PageDown, KeyCode::Up, KeyCode::Home] {
+            assert!(tab.navigate(key));
+            assert_eq!((tab.selected_item, tab.scroll_offset), (0, 0));
+        }
+        assert!(!tab.navigate(KeyCode::Char('x')));
+    }
+
+    #[test]
+    fn end_then_down_stays_on_last_item() {
+        let mut tab = drawn_tab(100, 10);
+        tab.navigate(KeyCode::End);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (99, 90));
+        tab.navigate(KeyCode::Down);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (99, 90));
+        tab.navigate(KeyCode::Char('g'));
+        assert_eq!((tab.selected_item, tab.scroll_offset), (0, 0));
+    }
+
+    #[test]
+    fn page_larger_than_list_never_scrolls() {
+        let mut tab = drawn_tab(5, 20);
+        tab.navigate(KeyCode::PageDown);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (4, 0));
+        tab.navigate(KeyCode::PageUp);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (0, 0));
+    }
+
+    #[test]
+    fn selection_scrolls_only_when_leaving_the_view() {
+        let mut tab = drawn_tab(100, 10);
+        for _ in 0..9 {
+            tab.navigate(KeyCode::Down);
+        }
+        assert_eq!((tab.selected_item, tab.scroll_offset), (9, 0));
+        tab.navigate(KeyCode::Down);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (10, 1));
+        tab.navigate(KeyCode::PageDown);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (20, 11));
+        tab.navigate(KeyCode::Up);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (19, 11));
+        tab.navigate(KeyCode::PageUp);
+        assert_eq!((tab.selected_item, tab.scroll_offset), (9, 9));
+    }
+
+    #[test]
+    fn visible_offset_follows_a_shrinking_list() {
+        let mut tab = drawn_tab(100, 10);
+        tab.navigate(KeyCode::End);
+        // The list shrank to 30 rows before the next draw
+        assert_eq!(tab.visible_offset(30, 10), 20);
+        assert_eq!(tab.visible_offset(5, 10), 0);
+        assert_eq!(tab.visible_offset(0, 10), 0);
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("90s"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(parse_duration("6h"), Some(Duration::from_secs(6 * 3600)));
+        assert_eq!(parse_duration("2d"), Some(Duration::from_secs(2 * 86400)));
+        // Bare numbers are hours
+        assert_eq!(parse_duration("3"), Some(Duration::from_secs(3 * 3600)));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        assert_eq!(parse_duration("0"), None);
+        assert_eq!(parse_duration("0s"), None);
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("5w"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("999999999999999d"), None);
+        assert_eq!(parse_duration("18446744073709551615m"), None);
+    }
+}
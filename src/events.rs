@@ -1,6 +1,15 @@
+use crate::system::diskusage::DirEntry;
+use crate::system::ProcessKey;
 use crossterm::event::Event as CrosstermEvent;
+use std::path::PathBuf;
 
 pub enum Event {
     Input(CrosstermEvent),
     Refresh,
+    ScanProgress(u64, u64),
+    ScanDone(u64, DirEntry),
+    /// A disk-usage entry was deleted, or failed to be, during that scan
+    DeleteDone(u64, PathBuf, Result<(), String>),
+    /// The TERM grace period ran out for these processes; KILL any still alive
+    KillGraceExpired(Vec<ProcessKey>),
 }
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub is_dir: bool,
+    pub children: Vec<DirEntry>,
+}
+
+/// Walks `root` without crossing into other filesystems, summing allocated
+/// blocks like `du -x`. Hard links are only counted once. `progress` is
+/// called with the running number of entries visited.
+pub fn scan(root: &Path, progress: &mut dyn FnMut(u64)) -> DirEntry {
+    let dev = std::fs::symlink_metadata(root).map(|m| m.dev()).unwrap_or(0);
+    let mut seen = HashSet::new();
+    let mut count = 0;
+    scan_dir(root, dev, &mut seen, &mut count, progress)
+}
+
+fn scan_dir(
+    path: &Path,
+    dev: u64,
+    seen: &mut HashSet<(u64, u64)>,
+    count: &mut u64,
+    progress: &mut dyn FnMut(u64),
+) -> DirEntry {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string());
+
+    let mut entry = DirEntry {
+        name,
+        path: path.to_path_buf(),
+        size: std::fs::symlink_metadata(path)
+            .map(|m| m.blocks() * 512)
+            .unwrap_or(0),
+        is_dir: true,
+        children: Vec::new(),
+    };
+
+    let Ok(read_dir) = std::fs::read_dir(path) else {
+        return entry;
+    };
+
+    for child in read_dir.flatten() {
+        let Ok(meta) = child.metadata() else {
+            continue;
+        };
+
+        *count += 1;
+        if count.is_multiple_of(1000) {
+            progress(*count);
+        }
+
+        let child_path = child.path();
+        if meta.is_dir() {
+            // Stay on one filesystem
+            if meta.dev() != dev {
+                continue;
+            }
+            let sub = scan_dir(&child_path, dev, seen, count, progress);
+            entry.size += sub.size;
+            entry.children.push(sub);
+        } else {
+            if meta.nlink() > 1 && !seen.insert((meta.dev(), meta.ino())) {
+                continue;
+            }
+            let size = meta.blocks() * 512;
+            entry.size += size;
+            entry.children.push(DirEntry {
+                name: child.file_name().to_string_lossy().to_string(),
+                path: child_path,
+                size,
+                is_dir: false,
+                children: Vec::new(),
+            });
+        }
+    }
+
+    entry.children.sort_by_key(|c| std::cmp::Reverse(c.size));
+    entry
+}
+
+/// Navigation state for the directory-size explorer tab.
+#[derive(Debug, Clone)]
+pub struct DiskExplorer {
+    pub scan_id: u64,
+    pub root_path: PathBuf,
+    pub root: Option<DirEntry>,
+    pub scanned: u64,
+    /// Indices into `children` from the root down to the current directory
+    pub path: Vec<usize>,
+}
+
+impl DiskExplorer {
+    pub fn new(scan_id: u64, root_path: PathBuf) -> Self {
+        Self {
+            scan_id,
+            root_path,
+            root: None,
+            scanned: 0,
+            path: Vec::new(),
+        }
+    }
+
+    pub fn current(&self) -> Option<&DirEntry> {
+        let mut dir = self.root.as_ref()?;
+        for &idx in &self.path {
+            dir = dir.children.get(idx)?;
+        }
+        Some(dir)
+    }
+
+    /// Descends into the child at `idx` if it is a directory.
+    pub fn enter(&mut self, idx: usize) -> bool {
+        match self.current().and_then(|d| d.children.get(idx)) {
+            Some(child) if child.is_dir => {
+                self.path.push(idx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Goes up one level, returning the index of the directory we left.
+    pub fn back(&mut self) -> Option<usize> {
+        self.path.pop()
+    }
+
+    /// Removes the entry at `path` from the in-memory tree after it has been
+    /// deleted from disk, subtracting its size from every ancestor. Looked
+    /// up by path, since a finished rescan may have replaced the tree.
+    pub fn remove(&mut self, path: &Path) {
+        // Indices into `children` from the root down to the entry
+        let mut indices = Vec::new();
+        let Some(mut dir) = self.root.as_ref() else {
+            return;
+        };
+        while dir.path != path {
+            let Some(idx) = dir.children.iter().position(|c| path.starts_with(&c.path)) else {
+                return;
+            };
+            indices.push(idx);
+            dir = &dir.children[idx];
+        }
+        let removed = dir.size;
+        let Some((&last, ancestors)) = indices.split_last() else {
+            // Never remove the scanned root itself
+            return;
+        };
+        let Some(mut dir) = self.root.as_mut() else {
+            return;
+        };
+
+        dir.size = dir.size.saturating_sub(removed);
+        for &i in ancestors {
+            dir = &mut dir.children[i];
+            dir.size = dir.size.saturating_sub(removed);
+        }
+        dir.children.remove(last);
+
+        // Leave a directory that was removed, and keep pointing at the same
+        // directory if a sibling before it went away
+        let depth = ancestors.len();
+        if self.path.starts_with(ancestors) {
+            match self.path.get(depth) {
+                Some(&i) if i == last => self.path.truncate(depth),
+                Some(&i) if i > last => self.path[depth] -= 1,
+                _ => {}
+            }
+        }
+    }
+}
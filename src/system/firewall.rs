@@ -0,0 +1,397 @@
+use std::process::Command;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub text: String,
+    pub packets: Option<u64>,
+    pub bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chain {
+    pub name: String,
+    /// Netfilter hook for base chains, e.g. `input`; None for regular chains
+    pub hook: Option<String>,
+    pub policy: Option<String>,
+    /// Policy counters, which only iptables reports
+    pub packets: Option<u64>,
+    pub bytes: Option<u64>,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub family: String,
+    pub name: String,
+    pub chains: Vec<Chain>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Ruleset {
+    /// Which tool the ruleset was read with, `nftables` or `iptables`
+    pub backend: &'static str,
+    pub tables: Vec<Table>,
+    pub error: Option<String>,
+}
+
+impl Ruleset {
+    pub fn rule_count(&self) -> usize {
+        self.tables
+            .iter()
+            .flat_map(|t| &t.chains)
+            .map(|c| c.rules.len())
+            .sum()
+    }
+}
+
+/// Reads the packet filter ruleset with `nft`, falling back to
+/// `iptables-save` on systems without nftables or when nft shows no
+/// tables. Both need root.
+pub fn read() -> Ruleset {
+    let mut errors = Vec::new();
+    let mut nft_empty = false;
+
+    match run("nft", &["-j", "list", "ruleset"]) {
+        Ok(json) => match parse_nft(&json) {
+            Some(tables) if !tables.is_empty() => {
+                return Ruleset {
+                    backend: "nftables",
+                    tables,
+                    error: None,
+                };
+            }
+            // Rules loaded with iptables-legacy don't show up in nft
+            Some(_) => nft_empty = true,
+            None => errors.push("nft: unexpected JSON output".to_string()),
+        },
+        Err(e) => errors.push(format!("nft: {}", e)),
+    }
+
+    let mut tables = Vec::new();
+    for (tool, family) in [("iptables-save", "ip"), ("ip6tables-save", "ip6")] {
+        match run(tool, &["-c"]) {
+            Ok(text) => tables.extend(parse_iptables_save(&text, family)),
+            Err(e) => errors.push(format!("{}: {}", tool, e)),
+        }
+    }
+    if !tables.is_empty() {
+        return Ruleset {
+            backend: "iptables",
+            tables,
+            error: None,
+        };
+    }
+    if nft_empty {
+        // No rules loaded at all
+        return Ruleset {
+            backend: "nftables",
+            tables,
+            error: None,
+        };
+    }
+
+    Ruleset {
+        backend: "",
+        tables,
+        error: Some(errors.join("; ")),
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr.lines().next().unwrap_or("failed").trim().to_string());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Parses `nft -j list ruleset`. The output is a flat list of table,
+/// chain and rule objects that refer to their parents by name.
+pub fn parse_nft(json_str: &str) -> Option<Vec<Table>> {
+    let json = serde_json::from_str::<serde_json::Value>(json_str).ok()?;
+    let mut tables: Vec<Table> = Vec::new();
+
+    for item in json["nftables"].as_array()? {
+        if let Some(table) = item.get("table") {
+            tables.push(Table {
+                family: str_field(table, "family"),
+                name: str_field(table, "name"),
+                chains: Vec::new(),
+            });
+        } else if let Some(chain) = item.get("chain") {
+            let Some(table) = find_table(&mut tables, chain) else {
+                continue;
+            };
+            table.chains.push(Chain {
+                name: str_field(chain, "name"),
+                hook: chain["hook"].as_str().map(String::from),
+                policy: chain["policy"].as_str().map(String::from),
+                packets: None,
+                bytes: None,
+                rules: Vec::new(),
+            });
+        } else if let Some(rule) = item.get("rule") {
+            let chain_name = str_field(rule, "chain");
+            let Some(chain) = find_table(&mut tables, rule).and_then(|t| t.chains.iter_mut().find(|c| c.name == chain_name))
+            else {
+                continue;
+            };
+
+            let exprs = rule["expr"].as_array().map(Vec::as_slice).unwrap_or_default();
+            let counter = exprs.iter().find_map(|e| e.get("counter"));
+            let mut parts: Vec<String> = exprs.iter().filter_map(nft_expr).collect();
+            if let Some(comment) = rule["comment"].as_str() {
+                parts.push(format!("comment \"{}\"", comment));
+            }
+
+            chain.rules.push(Rule {
+                text: parts.join(" "),
+                packets: counter.and_then(|c| c["packets"].as_u64()),
+                bytes: counter.and_then(|c| c["bytes"].as_u64()),
+            });
+        }
+    }
+
+    Some(tables)
+}
+
+fn str_field(value: &serde_json::Value, key: &str) -> String {
+    value[key].as_str().unwrap_or("-").to_string()
+}
+
+fn find_table<'a>(tables: &'a mut [Table], item: &serde_json::Value) -> Option<&'a mut Table> {
+    let (family, name) = (item["family"].as_str()?, item["table"].as_str()?);
+    tables.iter_mut().find(|t| t.family == family && t.name == name)
+}
+
+/// Renders one rule expression roughly the way `nft list ruleset` would.
+/// Counters are returned separately, so they render as nothing.
+fn nft_expr(expr: &serde_json::Value) -> Option<String> {
+    let (key, value) = expr.as_object()?.iter().next()?;
+
+    Some(match key.as_str() {
+        "match" => {
+            let left = nft_value(&value["left"]);
+            let right = nft_value(&value["right"]);
+            match value["op"].as_str() {
+                Some("==") | Some("in") | None => format!("{} {}", left, right),
+                Some(op) => format!("{} {} {}", left, op, right),
+            }
+        }
+        "counter" => return None,
+        "jump" | "goto" => format!("{} {}", key, value["target"].as_str().unwrap_or("?")),
+        "log" => match value["prefix"].as_str() {
+            Some(prefix) => format!("log prefix \"{}\"", prefix),
+            None => "log".to_string(),
+        },
+        "reject" => match value["type"].as_str() {
+            Some(kind) => format!("reject with {}", kind),
+            None => "reject".to_string(),
+        },
+        "limit" => format!(
+            "limit rate {}/{}",
+            value["rate"].as_u64().unwrap_or(0),
+            value["per"].as_str().unwrap_or("second")
+        ),
+        "snat" | "dnat" => {
+            let mut text = format!("{} to {}", key, nft_value(&value["addr"]));
+            if !value["port"].is_null() {
+                text.push_str(&format!(":{}", nft_value(&value["port"])));
+            }
+            text
+        }
+        // Verdicts and statements without arguments: accept, drop, masquerade...
+        _ if value.is_null() => key.clone(),
+        _ => format!("{} {}", key, nft_value(value)),
+    })
+}
+
+fn nft_value(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Null => String::new(),
+        // Flag lists such as ct state { established, related }
+        Value::Array(items) => items.iter().map(nft_value).collect::<Vec<_>>().join(","),
+        Value::Object(obj) => {
+            let Some((key, inner)) = obj.iter().next() else {
+                return String::new();
+            };
+            match key.as_str() {
+                "payload" => format!(
+                    "{} {}",
+                    inner["protocol"].as_str().unwrap_or("?"),
+                    inner["field"].as_str().unwrap_or("?")
+                ),
+                "meta" | "ct" => {
+                    let field = inner["key"].as_str().unwrap_or("?");
+                    if key == "ct" {
+                        format!("ct {}", field)
+                    } else {
+                        field.to_string()
+                    }
+                }
+                "set" => match inner {
+                    Value::Array(items) => format!(
+                        "{{ {} }}",
+                        items.iter().map(nft_value).collect::<Vec<_>>().join(", ")
+                    ),
+                    other => nft_value(other),
+                },
+                "prefix" => format!("{}/{}", nft_value(&inner["addr"]), nft_value(&inner["len"])),
+                "range" => match inner.as_array().map(Vec::as_slice) {
+                    Some([low, high]) => format!("{}-{}", nft_value(low), nft_value(high)),
+                    _ => nft_value(inner),
+                },
+                _ => format!("{} {}", key, nft_value(inner)),
+            }
+        }
+    }
+}
+
+/// Parses `iptables-save -c` output. `family` labels the tables, since
+/// the same dump format is used for IPv4 and IPv6.
+pub fn parse_iptables_save(text: &str, family: &str) -> Vec<Table> {
+    let mut tables: Vec<Table> = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('*') {
+            tables.push(Table {
+                family: family.to_string(),
+                name: name.to_string(),
+                chains: Vec::new(),
+            });
+            continue;
+        }
+        let Some(table) = tables.last_mut() else {
+            continue;
+        };
+
+        if let Some(decl) = line.strip_prefix(':') {
+            // ":INPUT ACCEPT [123:4567]", with "-" as the policy of user chains
+            let mut tokens = decl.split_whitespace();
+            let name = tokens.next().unwrap_or_default().to_string();
+            let policy = tokens.next().filter(|p| *p != "-").map(String::from);
+            let (packets, bytes) = tokens.next().and_then(parse_counters).unzip();
+            table.chains.push(Chain {
+                hook: policy.as_ref().map(|_| name.to_lowercase()),
+                name,
+                policy,
+                packets,
+                bytes,
+                rules: Vec::new(),
+            });
+        } else if line.starts_with("-A") || line.starts_with('[') {
+            // "[12:3456] -A INPUT -i lo -j ACCEPT" when saved with -c
+            let (counters, rule) = match line.split_once(' ') {
+                Some((first, rest)) if first.starts_with('[') => (parse_counters(first), rest),
+                _ => (None, line),
+            };
+            let Some(rest) = rule.strip_prefix("-A ") else {
+                continue;
+            };
+            let (chain_name, spec) = rest.split_once(' ').unwrap_or((rest, ""));
+            if let Some(chain) = table.chains.iter_mut().find(|c| c.name == chain_name) {
+                chain.rules.push(Rule {
+                    text: spec.to_string(),
+                    packets: counters.map(|(p, _)| p),
+                    bytes: counters.map(|(_, b)| b),
+                });
+            }
+        }
+    }
+
+    tables
+}
+
+/// Parses `[packets:bytes]`.
+fn parse_counters(token: &str) -> Option<(u64, u64)> {
+    let (packets, bytes) = token.strip_prefix('[')?.strip_suffix(']')?.split_once(':')?;
+    Some((packets.parse().ok()?, bytes.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nft_ruleset() {
+        let tables = parse_nft(include_str!("fixtures/nft_ruleset.json")).unwrap();
+        assert_eq!(tables.len(), 2);
+
+        let filter = &tables[0];
+        assert_eq!((filter.family.as_str(), filter.name.as_str()), ("inet", "filter"));
+        assert_eq!(filter.chains.len(), 3);
+
+        let input = &filter.chains[0];
+        assert_eq!(input.name, "input");
+        assert_eq!(input.hook.as_deref(), Some("input"));
+        assert_eq!(input.policy.as_deref(), Some("drop"));
+        assert_eq!(
+            input.rules.iter().map(|r| r.text.as_str()).collect::<Vec<_>>(),
+            vec![
+                "ct state established,related accept",
+                "iifname lo accept",
+                "tcp dport { 22, 80, 443 } accept",
+                "ip saddr 10.0.0.0/8 udp dport 5353 accept",
+                "ip protocol icmp limit rate 10/second accept",
+                "jump ssh_guard",
+                "log prefix \"dropped: \" drop comment \"default drop\"",
+            ]
+        );
+        assert_eq!(input.rules[0].packets, Some(48211));
+        assert_eq!(input.rules[0].bytes, Some(61874213));
+        assert_eq!(input.rules[5].packets, None);
+
+        let guard = &filter.chains[2];
+        assert_eq!(guard.name, "ssh_guard");
+        assert_eq!(guard.hook, None);
+        assert_eq!(guard.rules[0].text, "ip saddr != 192.168.1.0/24 tcp dport 22 reject with icmpx");
+
+        let nat = &tables[1];
+        assert_eq!(nat.chains[0].rules[0].text, "tcp dport 8080 dnat to 192.168.1.10:80");
+        assert_eq!(nat.chains[1].rules[0].text, "oifname eth0 masquerade");
+    }
+
+    #[test]
+    fn parses_iptables_save() {
+        let tables = parse_iptables_save(include_str!("fixtures/iptables_save.txt"), "ip");
+        assert_eq!(
+            tables.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            vec!["nat", "filter"]
+        );
+
+        let filter = &tables[1];
+        let input = &filter.chains[0];
+        assert_eq!(input.name, "INPUT");
+        assert_eq!(input.hook.as_deref(), Some("input"));
+        assert_eq!(input.policy.as_deref(), Some("DROP"));
+        assert_eq!((input.packets, input.bytes), (Some(1523), Some(91380)));
+        assert_eq!(input.rules.len(), 3);
+        assert_eq!(input.rules[1].text, "-m conntrack --ctstate RELATED,ESTABLISHED -j ACCEPT");
+        assert_eq!((input.rules[1].packets, input.rules[1].bytes), (Some(90211), Some(118340022)));
+
+        let docker = filter.chains.iter().find(|c| c.name == "DOCKER").unwrap();
+        assert_eq!(docker.policy, None);
+        assert_eq!(docker.hook, None);
+        assert_eq!(docker.rules[0].text, "-d 172.17.0.2/32 ! -i docker0 -o docker0 -p tcp -m tcp --dport 80 -j ACCEPT");
+    }
+
+    #[test]
+    fn handles_empty_and_invalid_output() {
+        assert_eq!(parse_nft("not json"), None);
+        assert_eq!(parse_nft(r#"{"nftables": [{"metainfo": {"version": "1.0.9"}}]}"#), Some(Vec::new()));
+        assert!(parse_iptables_save("", "ip").is_empty());
+        // Rules without -c carry no counters
+        let tables = parse_iptables_save("*filter\n:INPUT ACCEPT\n-A INPUT -j ACCEPT\nCOMMIT\n", "ip");
+        assert_eq!(tables[0].chains[0].rules[0].packets, None);
+        assert_eq!(tables[0].chains[0].packets, None);
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+use std::os::unix::fs::MetadataExt;
+
+/// `__SO_ACCEPTCON`, set in the flags of listening Unix sockets
+const SO_ACCEPTCON: u32 = 0x0001_0000;
+
+#[derive(Debug, Clone)]
+pub struct UnixSocket {
+    /// Filesystem path, `@name` for abstract sockets, or None if unbound
+    pub path: Option<String>,
+    pub kind: &'static str,
+    pub state: &'static str,
+    pub inode: u64,
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+}
+
+impl UnixSocket {
+    pub fn is_listening(&self) -> bool {
+        self.state == "LISTEN"
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SharedMemory {
+    pub key: i32,
+    pub id: u32,
+    pub perms: u32,
+    pub size: u64,
+    pub attached: u32,
+    pub creator_pid: u32,
+    pub uid: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SemaphoreSet {
+    pub key: i32,
+    pub id: u32,
+    pub perms: u32,
+    pub count: u32,
+    pub uid: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct MessageQueue {
+    pub key: i32,
+    pub id: u32,
+    pub perms: u32,
+    pub bytes: u64,
+    pub messages: u64,
+    pub uid: u32,
+}
+
+/// A POSIX shared memory object or named semaphore in `/dev/shm`.
+#[derive(Debug, Clone)]
+pub struct PosixObject {
+    pub name: String,
+    pub kind: &'static str,
+    pub size: u64,
+    pub uid: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IpcInfo {
+    pub unix_sockets: Vec<UnixSocket>,
+    pub shared_memory: Vec<SharedMemory>,
+    pub semaphores: Vec<SemaphoreSet>,
+    pub message_queues: Vec<MessageQueue>,
+    pub posix: Vec<PosixObject>,
+}
+
+/// Reads Unix sockets and SysV/POSIX IPC objects. `owners` maps socket
+/// inodes to PIDs, as returned by `sockets::socket_owners`.
+pub fn read(owners: &HashMap<u64, u32>) -> IpcInfo {
+    let sysvipc = |name: &str| std::fs::read_to_string(format!("/proc/sysvipc/{}", name)).unwrap_or_default();
+
+    IpcInfo {
+        unix_sockets: read_unix_sockets(owners),
+        shared_memory: parse_sysv_shm(&sysvipc("shm")),
+        semaphores: parse_sysv_sem(&sysvipc("sem")),
+        message_queues: parse_sysv_msg(&sysvipc("msg")),
+        posix: read_posix(),
+    }
+}
+
+fn read_unix_sockets(owners: &HashMap<u64, u32>) -> Vec<UnixSocket> {
+    let mut names: HashMap<u32, Option<String>> = HashMap::new();
+    let content = std::fs::read_to_string("/proc/net/unix").unwrap_or_default();
+
+    let mut sockets: Vec<UnixSocket> = parse_unix(&content)
+        .into_iter()
+        .map(|mut sock| {
+            sock.pid = owners.get(&sock.inode).copied();
+            sock.process = sock.pid.and_then(|pid| {
+                names
+                    .entry(pid)
+                    .or_insert_with(|| {
+                        std::fs::read_to_string(format!("/proc/{}/comm", pid))
+                            .ok()
+                            .map(|s| s.trim().to_string())
+                    })
+                    .clone()
+            });
+            sock
+        })
+        .collect();
+
+    // Listening sockets first, then named ones, like the Connections view
+    sockets.sort_by(|a, b| {
+        (!a.is_listening(), a.path.is_none(), &a.path).cmp(&(!b.is_listening(), b.path.is_none(), &b.path))
+    });
+    sockets
+}
+
+fn parse_unix(content: &str) -> Vec<UnixSocket> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            // Num RefCount Protocol Flags Type St Inode [Path]
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            let flags = u32::from_str_radix(fields[3], 16).ok()?;
+            let kind = match u16::from_str_radix(fields[4], 16).ok()? {
+                1 => "stream",
+                2 => "dgram",
+                5 => "seqpacket",
+                _ => "other",
+            };
+            let state = if flags & SO_ACCEPTCON != 0 {
+                "LISTEN"
+            } else {
+                match u8::from_str_radix(fields[5], 16).ok()? {
+                    1 => "UNCONN",
+                    2 => "CONNECTING",
+                    3 => "CONNECTED",
+                    4 => "DISCONNECTING",
+                    _ => "UNKNOWN",
+                }
+            };
+
+            Some(UnixSocket {
+                // Paths may contain spaces, so take the rest of the line
+                path: (fields.len() > 7).then(|| fields[7..].join(" ")),
+                kind,
+                state,
+                inode: fields[6].parse().unwrap_or(0),
+                pid: None,
+                process: None,
+            })
+        })
+        .collect()
+}
+
+/// Splits a `/proc/sysvipc` table into rows of named columns.
+fn sysv_rows(content: &str) -> Vec<HashMap<&str, &str>> {
+    let mut lines = content.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.split_whitespace().collect();
+
+    lines
+        .map(|line| columns.iter().copied().zip(line.split_whitespace()).collect())
+        .collect()
+}
+
+fn parse_sysv_shm(content: &str) -> Vec<SharedMemory> {
+    sysv_rows(content)
+        .iter()
+        .filter_map(|row| {
+            Some(SharedMemory {
+                key: row.get("key")?.parse().ok()?,
+                id: row.get("shmid")?.parse().ok()?,
+                perms: u32::from_str_radix(row.get("perms")?, 8).ok()?,
+                size: row.get("size")?.parse().ok()?,
+                attached: row.get("nattch")?.parse().ok()?,
+                creator_pid: row.get("cpid")?.parse().ok()?,
+                uid: row.get("uid")?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+fn parse_sysv_sem(content: &str) -> Vec<SemaphoreSet> {
+    sysv_rows(content)
+        .iter()
+        .filter_map(|row| {
+            Some(SemaphoreSet {
+                key: row.get("key")?.parse().ok()?,
+                id: row.get("semid")?.parse().ok()?,
+                perms: u32::from_str_radix(row.get("perms")?, 8).ok()?,
+                count: row.get("nsems")?.parse().ok()?,
+                uid: row.get("uid")?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+fn parse_sysv_msg(content: &str) -> Vec<MessageQueue> {
+    sysv_rows(content)
+        .iter()
+        .filter_map(|row| {
+            Some(MessageQueue {
+                key: row.get("key")?.parse().ok()?,
+                id: row.get("msqid")?.parse().ok()?,
+                perms: u32::from_str_radix(row.get("perms")?, 8).ok()?,
+                bytes: row.get("cbytes")?.parse().ok()?,
+                messages: row.get("qnum")?.parse().ok()?,
+                uid: row.get("uid")?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// POSIX shared memory and named semaphores are files on the `/dev/shm`
+/// tmpfs; semaphores carry a `sem.` prefix.
+fn read_posix() -> Vec<PosixObject> {
+    let Ok(entries) = std::fs::read_dir("/dev/shm") else {
+        return Vec::new();
+    };
+
+    let mut objects: Vec<PosixObject> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            if !meta.is_file() {
+                return None;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            Some(PosixObject {
+                kind: if name.starts_with("sem.") { "sem" } else { "shm" },
+                name,
+                size: meta.len(),
+                uid: meta.uid(),
+            })
+        })
+        .collect();
+    objects.sort_by(|a, b| a.name.cmp(&b.name));
+    objects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unix_sockets() {
+        let sockets = parse_unix(include_str!("fixtures/proc_net_unix.txt"));
+        assert_eq!(
+            sockets
+                .iter()
+                .map(|s| (s.path.as_deref(), s.kind, s.state, s.inode))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("/run/systemd/private"), "stream", "LISTEN", 18203),
+                (Some("@/tmp/.X11-unix/X0"), "stream", "LISTEN", 21987),
+                (Some("/run/systemd/notify"), "dgram", "UNCONN", 14811),
+                (None, "stream", "CONNECTED", 40213),
+                (Some("/run/user/1000/My Socket"), "seqpacket", "LISTEN", 25090),
+                (None, "stream", "CONNECTING", 40377),
+            ]
+        );
+        assert!(parse_unix("").is_empty());
+    }
+
+    #[test]
+    fn parses_sysv_shared_memory() {
+        let segments = parse_sysv_shm(include_str!("fixtures/sysvipc_shm.txt"));
+        assert_eq!(segments.len(), 2);
+
+        let postgres = &segments[0];
+        assert_eq!((postgres.key, postgres.id), (5432001, 0));
+        assert_eq!(postgres.perms, 0o600);
+        assert_eq!(postgres.size, 56064);
+        assert_eq!((postgres.attached, postgres.creator_pid, postgres.uid), (6, 1342, 113));
+
+        // Marked for removal once the last process detaches
+        assert_eq!(segments[1].perms, 0o1600);
+    }
+
+    #[test]
+    fn parses_sysv_semaphores_and_queues() {
+        let sets = parse_sysv_sem(include_str!("fixtures/sysvipc_sem.txt"));
+        assert_eq!(sets.len(), 1);
+        assert_eq!((sets[0].key, sets[0].id, sets[0].perms), (-1093467, 32768, 0o666));
+        assert_eq!((sets[0].count, sets[0].uid), (1, 1000));
+
+        let queues = parse_sysv_msg(include_str!("fixtures/sysvipc_msg.txt"));
+        assert_eq!(queues.len(), 1);
+        assert_eq!((queues[0].key, queues[0].perms), (0x12345678, 0o644));
+        assert_eq!((queues[0].bytes, queues[0].messages, queues[0].uid), (512, 4, 0));
+    }
+
+    #[test]
+    fn handles_empty_sysv_tables() {
+        assert!(parse_sysv_shm("").is_empty());
+        // Only the header when no objects exist
+        assert!(parse_sysv_sem("       key      semid perms      nsems   uid   gid  cuid  cgid      otime      ctime\n").is_empty());
+    }
+}
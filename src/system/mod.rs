@@ -1,5 +1,58 @@
-use sysinfo::{Disks, Networks, System};
+pub mod diskusage;
+pub mod firewall;
+pub mod ipc;
+pub mod netconfig;
+pub mod netdev;
+pub mod procdetail;
+pub mod procfilter;
+pub mod procstat;
+pub mod proctree;
+pub mod sched;
+pub mod signal;
+pub mod smart;
+pub mod sockets;
+pub mod storage;
+pub mod threads;
+
+use firewall::Ruleset;
+use ipc::IpcInfo;
+use netconfig::NetConfig;
+use netdev::{LinkErrors, LinkInfo, ProtocolCounter, WirelessInfo};
+use smart::SmartInfo;
+use sockets::Connection;
+use storage::StorageStack;
+use sysinfo::{Disks, Networks, System, ThreadKind, Users};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
 use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// SMART queries spin up `smartctl` per disk, so they run far less often
+/// than the regular refresh.
+const SMART_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Collections that spawn commands or walk every process's open files, so
+/// they only run while a tab shows their data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Collections {
+    /// Neighbors and policy rules from `ip`
+    pub net_config: bool,
+    /// Ruleset from `nft` or `iptables-save`
+    pub firewall: bool,
+    /// TCP/UDP and Unix sockets, whose owners come from every `/proc/*/fd`
+    pub sockets: bool,
+    /// SSID, frequency and bitrate of wireless links from `iw`
+    pub wireless: bool,
+}
+
+/// How much used-space history is kept per mount for fill-rate estimates.
+const DISK_HISTORY_WINDOW: Duration = Duration::from_secs(30 * 60);
+
+/// Minimum span of history before a fill rate is trusted.
+const DISK_HISTORY_MIN_SPAN: Duration = Duration::from_secs(60);
+
+/// Number of rate samples kept per interface for the history charts.
+const NETWORK_HISTORY_LEN: usize = 120;
 
 #[derive(Debug, Clone)]
 pub struct CpuInfo {
@@ -22,6 +75,10 @@ pub struct DiskInfo {
     pub total: u64,
     pub available: u64,
     pub file_system: String,
+    /// Growth of used space in bytes per second (negative when shrinking)
+    pub fill_rate: Option<f64>,
+    /// Estimated time until the mount is full at the current fill rate
+    pub time_to_full: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
@@ -29,15 +86,244 @@ pub struct NetworkInfo {
     pub name: String,
     pub received: u64,
     pub transmitted: u64,
+    /// Current rates since the last refresh, per second
+    pub rx_rate: f64,
+    pub tx_rate: f64,
+    pub rx_packet_rate: f64,
+    pub tx_packet_rate: f64,
+    /// Highest byte rates seen since start
+    pub peak_rx_rate: f64,
+    pub peak_tx_rate: f64,
+    pub link: LinkInfo,
+    pub errors: LinkErrors,
+    pub wireless: Option<WirelessInfo>,
+}
+
+/// Recent byte rates per interface, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkHistory {
+    pub rx: VecDeque<u64>,
+    pub tx: VecDeque<u64>,
+    /// Signal level in dB above -100 dBm, for wireless interfaces
+    pub signal: VecDeque<u64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProcessInfo {
     pub pid: u32,
+    pub parent_pid: Option<u32>,
     pub name: String,
+    pub cmdline: String,
     pub cpu_usage: f32,
+    /// Resident and virtual memory in bytes
     pub memory: u64,
+    pub virtual_memory: u64,
     pub status: String,
+    pub user: String,
+    pub threads: u32,
+    pub nice: i64,
+    pub priority: i64,
+    /// Accumulated user + system CPU time in seconds
+    pub cpu_time: f64,
+    /// Start time as seconds since the Unix epoch
+    pub start_time: u64,
+    /// Start time in clock ticks since boot, see [`ProcessKey`]
+    pub start_ticks: u64,
+    /// Seconds since the process started
+    pub run_time: u64,
+    pub tty: Option<String>,
+    /// Disk read/write rates in bytes per second since the last refresh
+    pub read_rate: f64,
+    pub write_rate: f64,
+    pub total_read: u64,
+    pub total_written: u64,
+}
+
+/// A PID plus the process's start time. Unlike a bare PID, this can't
+/// silently come to refer to a different process once the original exits
+/// and the PID is reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessKey {
+    pub pid: u32,
+    pub start_ticks: u64,
+}
+
+impl ProcessKey {
+    /// Checks that the PID still belongs to the process it was taken from.
+    pub fn verify(&self) -> Result<(), String> {
+        let path = Path::new("/proc").join(self.pid.to_string()).join("stat");
+        match procstat::read(&path) {
+            None => Err("no such process (it has already exited)".to_string()),
+            Some(stat) if stat.start_ticks != self.start_ticks => {
+                Err("PID now belongs to a different process; refusing to signal it".to_string())
+            }
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+impl ProcessInfo {
+    pub fn key(&self) -> ProcessKey {
+        ProcessKey {
+            pid: self.pid,
+            start_ticks: self.start_ticks,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ProcessInfo {
+    /// A process with everything but its identity zeroed, for tests to
+    /// fill in the fields they care about.
+    pub fn for_test(pid: u32, parent_pid: Option<u32>, name: &str) -> Self {
+        Self {
+            pid,
+            parent_pid,
+            name: name.to_string(),
+            cmdline: String::new(),
+            cpu_usage: 0.0,
+            memory: 0,
+            virtual_memory: 0,
+            status: "Sleeping".to_string(),
+            user: String::new(),
+            threads: 1,
+            nice: 0,
+            priority: 20,
+            cpu_time: 0.0,
+            start_time: 0,
+            start_ticks: u64::from(pid),
+            run_time: 0,
+            tty: None,
+            read_rate: 0.0,
+            write_rate: 0.0,
+            total_read: 0,
+            total_written: 0,
+        }
+    }
+}
+
+/// Process table columns, in display order. Any of them can be shown,
+/// hidden or sorted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessColumn {
+    Pid,
+    Name,
+    User,
+    Cpu,
+    Memory,
+    Virtual,
+    DiskRead,
+    DiskWrite,
+    Threads,
+    Nice,
+    Priority,
+    CpuTime,
+    StartTime,
+    Runtime,
+    Tty,
+    Status,
+    Command,
+}
+
+impl ProcessColumn {
+    pub fn all() -> [ProcessColumn; 17] {
+        [
+            ProcessColumn::Pid,
+            ProcessColumn::Name,
+            ProcessColumn::User,
+            ProcessColumn::Cpu,
+            ProcessColumn::Memory,
+            ProcessColumn::Virtual,
+            ProcessColumn::DiskRead,
+            ProcessColumn::DiskWrite,
+            ProcessColumn::Threads,
+            ProcessColumn::Nice,
+            ProcessColumn::Priority,
+            ProcessColumn::CpuTime,
+            ProcessColumn::StartTime,
+            ProcessColumn::Runtime,
+            ProcessColumn::Tty,
+            ProcessColumn::Status,
+            ProcessColumn::Command,
+        ]
+    }
+
+    /// Columns shown until the user picks their own.
+    pub fn is_default(&self) -> bool {
+        matches!(
+            self,
+            ProcessColumn::Pid
+                | ProcessColumn::Name
+                | ProcessColumn::User
+                | ProcessColumn::Cpu
+                | ProcessColumn::Memory
+                | ProcessColumn::DiskRead
+                | ProcessColumn::DiskWrite
+                | ProcessColumn::Runtime
+                | ProcessColumn::Status
+        )
+    }
+
+    /// Table header text.
+    pub fn title(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Name => "Name",
+            ProcessColumn::User => "User",
+            ProcessColumn::Cpu => "CPU %",
+            ProcessColumn::Memory => "Memory",
+            ProcessColumn::Virtual => "Virtual",
+            ProcessColumn::DiskRead => "Read/s",
+            ProcessColumn::DiskWrite => "Write/s",
+            ProcessColumn::Threads => "Thr",
+            ProcessColumn::Nice => "NI",
+            ProcessColumn::Priority => "PRI",
+            ProcessColumn::CpuTime => "CPU Time",
+            ProcessColumn::StartTime => "Start",
+            ProcessColumn::Runtime => "Runtime",
+            ProcessColumn::Tty => "TTY",
+            ProcessColumn::Status => "Status",
+            ProcessColumn::Command => "Command",
+        }
+    }
+
+    /// Name used in status messages and the column chooser.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProcessColumn::Pid => "PID",
+            ProcessColumn::Name => "name",
+            ProcessColumn::User => "user",
+            ProcessColumn::Cpu => "CPU",
+            ProcessColumn::Memory => "memory (RSS)",
+            ProcessColumn::Virtual => "virtual size (VSZ)",
+            ProcessColumn::DiskRead => "disk read",
+            ProcessColumn::DiskWrite => "disk write",
+            ProcessColumn::Threads => "threads",
+            ProcessColumn::Nice => "nice",
+            ProcessColumn::Priority => "priority",
+            ProcessColumn::CpuTime => "CPU time",
+            ProcessColumn::StartTime => "start time",
+            ProcessColumn::Runtime => "runtime",
+            ProcessColumn::Tty => "TTY",
+            ProcessColumn::Status => "status",
+            ProcessColumn::Command => "command line",
+        }
+    }
+
+    /// Text columns read best A-Z, numeric ones biggest first.
+    pub fn default_ascending(&self) -> bool {
+        matches!(
+            self,
+            ProcessColumn::Pid
+                | ProcessColumn::Name
+                | ProcessColumn::User
+                | ProcessColumn::Nice
+                | ProcessColumn::Priority
+                | ProcessColumn::Tty
+                | ProcessColumn::Status
+                | ProcessColumn::Command
+        )
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -68,14 +354,26 @@ pub struct SystemData {
     sys: System,
     disks: Disks,
     networks: Networks,
+    user_names: HashMap<u32, String>,
     pub overview: OverviewInfo,
     pub cpus: Vec<CpuInfo>,
     pub memory: MemoryInfo,
     pub disk_list: Vec<DiskInfo>,
+    disk_history: HashMap<String, VecDeque<(Instant, u64)>>,
     pub network_list: Vec<NetworkInfo>,
+    pub network_history: HashMap<String, NetworkHistory>,
+    pub protocol_counters: Vec<ProtocolCounter>,
+    pub connections: Vec<Connection>,
+    pub net_config: NetConfig,
+    pub ipc: IpcInfo,
+    pub firewall: Ruleset,
     pub processes: Vec<ProcessInfo>,
     pub devices: Vec<DeviceInfo>,
+    pub smart: HashMap<String, SmartInfo>,
+    smart_refreshed: Option<Instant>,
+    pub storage: StorageStack,
     pub logs: Vec<String>,
+    last_refresh: Option<Instant>,
 }
 
 impl SystemData {
@@ -103,25 +401,48 @@ impl SystemData {
                 swap_used: 0,
             },
             disk_list: Vec::new(),
+            disk_history: HashMap::new(),
             network_list: Vec::new(),
+            network_history: HashMap::new(),
+            protocol_counters: Vec::new(),
+            connections: Vec::new(),
+            net_config: NetConfig::default(),
+            ipc: IpcInfo::default(),
+            firewall: Ruleset::default(),
             processes: Vec::new(),
             devices: Vec::new(),
+            smart: HashMap::new(),
+            smart_refreshed: None,
+            storage: StorageStack::default(),
             logs: Vec::new(),
+            last_refresh: None,
+            user_names: Users::new_with_refreshed_list()
+                .list()
+                .iter()
+                .map(|user| (**user.id(), user.name().to_string()))
+                .collect(),
             sys,
             disks,
             networks,
         };
 
-        data.refresh();
+        data.refresh(Collections::default());
         data
     }
 
-    pub fn refresh(&mut self) {
+    pub fn refresh(&mut self, wanted: Collections) {
         // Refresh system info
         self.sys.refresh_all();
         self.disks.refresh();
         self.networks.refresh();
 
+        let now = Instant::now();
+        let elapsed = self
+            .last_refresh
+            .map(|t| now.duration_since(t).as_secs_f64())
+            .unwrap_or(0.0);
+        self.last_refresh = Some(now);
+
         // Update overview
         self.overview.uptime = System::uptime();
 
@@ -149,48 +470,262 @@ impl SystemData {
         self.disk_list = self
             .disks
             .iter()
-            .map(|disk| DiskInfo {
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                total: disk.total_space(),
-                available: disk.available_space(),
-                file_system: disk.file_system().to_string_lossy().to_string(),
+            .map(|disk| {
+                let mount_point = disk.mount_point().to_string_lossy().to_string();
+                let total = disk.total_space();
+                let available = disk.available_space();
+
+                // Track used space so we can estimate when the mount fills up
+                let history = self.disk_history.entry(mount_point.clone()).or_default();
+                history.push_back((now, total.saturating_sub(available)));
+                while history
+                    .front()
+                    .is_some_and(|(t, _)| now.duration_since(*t) > DISK_HISTORY_WINDOW)
+                {
+                    history.pop_front();
+                }
+
+                let fill_rate = fill_rate(history);
+                // A near-zero slope means the mount effectively never fills
+                let time_to_full = fill_rate
+                    .filter(|&rate| rate > 0.0)
+                    .and_then(|rate| Duration::try_from_secs_f64(available as f64 / rate).ok());
+
+                DiskInfo {
+                    mount_point,
+                    total,
+                    available,
+                    file_system: disk.file_system().to_string_lossy().to_string(),
+                    fill_rate,
+                    time_to_full,
+                }
             })
             .collect();
 
+        let mounts: Vec<&String> = self.disk_list.iter().map(|d| &d.mount_point).collect();
+        self.disk_history.retain(|mount, _| mounts.contains(&mount));
+
         // Update network info
+        let rate = |count: u64| if elapsed > 0.0 { count as f64 / elapsed } else { 0.0 };
+        let previous = std::mem::take(&mut self.network_list);
+        let mut wireless = netdev::read_wireless();
         self.network_list = self
             .networks
             .iter()
-            .map(|(name, data)| NetworkInfo {
-                name: name.clone(),
-                received: data.total_received(),
-                transmitted: data.total_transmitted(),
+            .map(|(name, data)| {
+                let rx_rate = rate(data.received());
+                let tx_rate = rate(data.transmitted());
+                let prev = previous.iter().find(|n| &n.name == name);
+                let (peak_rx, peak_tx) = prev
+                    .map(|n| (n.peak_rx_rate, n.peak_tx_rate))
+                    .unwrap_or((0.0, 0.0));
+
+                let mut link = netdev::read_link_info(name);
+                for ip in data.ip_networks() {
+                    let addr = format!("{}/{}", ip.addr, ip.prefix);
+                    if ip.addr.is_ipv4() {
+                        link.ipv4.push(addr);
+                    } else {
+                        link.ipv6.push(addr);
+                    }
+                }
+
+                NetworkInfo {
+                    name: name.clone(),
+                    received: data.total_received(),
+                    transmitted: data.total_transmitted(),
+                    rx_rate,
+                    tx_rate,
+                    rx_packet_rate: rate(data.packets_received()),
+                    tx_packet_rate: rate(data.packets_transmitted()),
+                    peak_rx_rate: peak_rx.max(rx_rate),
+                    peak_tx_rate: peak_tx.max(tx_rate),
+                    link,
+                    errors: LinkErrors::read(name, prev.map(|n| &n.errors), elapsed),
+                    wireless: wireless.remove(name).map(|mut info| {
+                        // Keep what `iw` last said until it is asked again
+                        if let Some(prev) = prev.and_then(|n| n.wireless.as_ref()) {
+                            info.ssid = prev.ssid.clone();
+                            info.frequency = prev.frequency;
+                            info.bitrate = prev.bitrate;
+                        }
+                        info
+                    }),
+                }
             })
             .collect();
+        self.network_list.sort_by(|a, b| a.name.cmp(&b.name));
 
-        // Update process list
+        if elapsed > 0.0 {
+            for net in &self.network_list {
+                let history = self.network_history.entry(net.name.clone()).or_default();
+                history.rx.push_back(net.rx_rate as u64);
+                history.tx.push_back(net.tx_rate as u64);
+                if history.rx.len() > NETWORK_HISTORY_LEN {
+                    history.rx.pop_front();
+                    history.tx.pop_front();
+                }
+                if let Some(signal) = net.wireless.as_ref().and_then(|w| w.signal_dbm) {
+                    history.signal.push_back((signal + 100.0).max(0.0) as u64);
+                    if history.signal.len() > NETWORK_HISTORY_LEN {
+                        history.signal.pop_front();
+                    }
+                }
+            }
+        }
+        let names: Vec<&String> = self.network_list.iter().map(|n| &n.name).collect();
+        self.network_history.retain(|name, _| names.contains(&name));
+
+        self.protocol_counters = netdev::read_protocol_counters(&self.protocol_counters, elapsed);
+
+        // Update process list. sysinfo also lists every thread of a process
+        // as a process of its own; those are shown through the thread view
         self.processes = self
             .sys
             .processes()
             .iter()
-            .map(|(pid, proc)| ProcessInfo {
-                pid: pid.as_u32(),
-                name: proc.name().to_string_lossy().to_string(),
-                cpu_usage: proc.cpu_usage(),
-                memory: proc.memory(),
-                status: format!("{:?}", proc.status()),
+            .filter(|(_, proc)| proc.thread_kind() != Some(ThreadKind::Userland))
+            .map(|(pid, proc)| {
+                let io = proc.disk_usage();
+                let stat = procstat::read(&Path::new("/proc").join(pid.as_u32().to_string()).join("stat"))
+                    .unwrap_or_default();
+                ProcessInfo {
+                    pid: pid.as_u32(),
+                    parent_pid: proc.parent().map(|ppid| ppid.as_u32()),
+                    name: proc.name().to_string_lossy().to_string(),
+                    cmdline: proc
+                        .cmd()
+                        .iter()
+                        .map(|arg| arg.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    cpu_usage: proc.cpu_usage(),
+                    memory: proc.memory(),
+                    virtual_memory: proc.virtual_memory(),
+                    status: proc.status().to_string(),
+                    user: proc
+                        .user_id()
+                        .map(|uid| self.user_name(**uid))
+                        .unwrap_or_else(|| "-".to_string()),
+                    threads: stat.threads,
+                    nice: stat.nice,
+                    priority: stat.priority,
+                    cpu_time: stat.cpu_time(),
+                    start_time: proc.start_time(),
+                    start_ticks: stat.start_ticks,
+                    run_time: proc.run_time(),
+                    tty: procstat::tty_name(stat.tty_nr),
+                    read_rate: rate(io.read_bytes),
+                    write_rate: rate(io.written_bytes),
+                    total_read: io.total_read_bytes,
+                    total_written: io.total_written_bytes,
+                }
             })
             .collect();
 
-        // Sort processes by CPU usage (descending)
-        self.processes
-            .sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
-
         // Update devices
         self.refresh_devices();
 
+        // Update md/dm storage stack
+        self.storage = storage::read();
+
         // Update logs (last 50 lines from dmesg)
         self.refresh_logs();
+
+        self.refresh_collections(wanted);
+    }
+
+    /// Runs the collections that are skipped while no tab shows them.
+    pub fn refresh_collections(&mut self, wanted: Collections) {
+        if wanted.sockets {
+            // Update TCP/UDP sockets
+            let owners = sockets::socket_owners();
+            self.connections = sockets::read_connections(&owners);
+
+            // Update Unix sockets and SysV/POSIX IPC objects
+            self.ipc = ipc::read(&owners);
+        }
+
+        // Update SSID, frequency and bitrate of wireless links
+        if wanted.wireless {
+            for net in &mut self.network_list {
+                if let Some(info) = net.wireless.as_mut() {
+                    netdev::read_iw_link(&net.name, info);
+                }
+            }
+        }
+
+        // Update packet filter rules and counters
+        if wanted.firewall {
+            self.firewall = firewall::read();
+        }
+
+        // Update routes, neighbors and resolver config
+        if wanted.net_config {
+            self.net_config = netconfig::read();
+        }
+    }
+
+    /// Copy of the collected data for display while refreshing is paused.
+    /// The copy can't refresh itself: the sysinfo handles are left empty.
+    pub fn snapshot(&self) -> Self {
+        Self {
+            sys: System::new(),
+            disks: Disks::new(),
+            networks: Networks::new(),
+            user_names: self.user_names.clone(),
+            overview: self.overview.clone(),
+            cpus: self.cpus.clone(),
+            memory: self.memory.clone(),
+            disk_list: self.disk_list.clone(),
+            disk_history: self.disk_history.clone(),
+            network_list: self.network_list.clone(),
+            network_history: self.network_history.clone(),
+            protocol_counters: self.protocol_counters.clone(),
+            connections: self.connections.clone(),
+            net_config: self.net_config.clone(),
+            ipc: self.ipc.clone(),
+            firewall: self.firewall.clone(),
+            processes: self.processes.clone(),
+            devices: self.devices.clone(),
+            smart: self.smart.clone(),
+            smart_refreshed: self.smart_refreshed,
+            storage: self.storage.clone(),
+            logs: self.logs.clone(),
+            last_refresh: self.last_refresh,
+        }
+    }
+
+    /// Sorts the process list by the given column, breaking ties by PID so
+    /// rows don't shuffle between refreshes.
+    pub fn sort_processes(&mut self, sort: ProcessColumn, ascending: bool) {
+        let compare = |a: &ProcessInfo, b: &ProcessInfo| match sort {
+            ProcessColumn::Pid => a.pid.cmp(&b.pid),
+            ProcessColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            ProcessColumn::User => a.user.cmp(&b.user),
+            ProcessColumn::Cpu => a.cpu_usage.total_cmp(&b.cpu_usage),
+            ProcessColumn::Memory => a.memory.cmp(&b.memory),
+            ProcessColumn::Virtual => a.virtual_memory.cmp(&b.virtual_memory),
+            ProcessColumn::DiskRead => a.read_rate.total_cmp(&b.read_rate),
+            ProcessColumn::DiskWrite => a.write_rate.total_cmp(&b.write_rate),
+            ProcessColumn::Threads => a.threads.cmp(&b.threads),
+            ProcessColumn::Nice => a.nice.cmp(&b.nice),
+            ProcessColumn::Priority => a.priority.cmp(&b.priority),
+            ProcessColumn::CpuTime => a.cpu_time.total_cmp(&b.cpu_time),
+            ProcessColumn::StartTime => a.start_time.cmp(&b.start_time),
+            ProcessColumn::Runtime => a.run_time.cmp(&b.run_time),
+            ProcessColumn::Tty => a.tty.cmp(&b.tty),
+            ProcessColumn::Status => a.status.cmp(&b.status),
+            ProcessColumn::Command => a.cmdline.cmp(&b.cmdline),
+        };
+        self.processes.sort_by(|a, b| {
+            let ordering = compare(a, b).then(a.pid.cmp(&b.pid));
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
     }
 
     fn refresh_devices(&mut self) {
@@ -212,6 +747,14 @@ impl SystemData {
             }
         }
 
+        // Query SMART health for whole disks
+        if self
+            .smart_refreshed
+            .is_none_or(|t| t.elapsed() >= SMART_REFRESH_INTERVAL)
+        {
+            self.refresh_smart();
+        }
+
         // Get input devices
         if let Ok(entries) = std::fs::read_dir("/sys/class/input") {
             for entry in entries.flatten() {
@@ -290,6 +833,37 @@ impl SystemData {
         }
     }
 
+    fn refresh_smart(&mut self) {
+        self.smart_refreshed = Some(Instant::now());
+        self.smart = self
+            .devices
+            .iter()
+            .filter(|d| d.subsystem == "block" && d.device_type == "disk")
+            .filter(|d| !d.name.starts_with("zram") && !d.name.starts_with("loop"))
+            .filter_map(|d| smart::query(&d.name).map(|info| (d.name.clone(), info)))
+            .collect();
+    }
+
+    /// SMART warnings per disk, for surfacing outside the device popup.
+    pub fn smart_warnings(&self) -> Vec<(String, Vec<String>)> {
+        let mut warnings: Vec<(String, Vec<String>)> = self
+            .smart
+            .iter()
+            .map(|(name, info)| (name.clone(), info.warnings()))
+            .filter(|(_, w)| !w.is_empty())
+            .collect();
+        warnings.sort();
+        warnings
+    }
+
+    /// Login name for a UID, falling back to the number itself.
+    pub fn user_name(&self, uid: u32) -> String {
+        self.user_names
+            .get(&uid)
+            .cloned()
+            .unwrap_or_else(|| uid.to_string())
+    }
+
     fn parse_device(&mut self, dev: &serde_json::Value, subsystem: &str) {
         let name = dev["name"].as_str().unwrap_or("unknown").to_string();
         let device_type = dev["type"].as_str().unwrap_or("unknown").to_string();
@@ -397,6 +971,41 @@ impl SystemData {
     }
 }
 
+/// Reads a small sysfs/procfs attribute, trimming the trailing newline.
+fn read_trimmed(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Least-squares slope of used bytes over time, in bytes per second.
+fn fill_rate(history: &VecDeque<(Instant, u64)>) -> Option<f64> {
+    let (first, _) = history.front()?;
+    let (last, _) = history.back()?;
+    if last.duration_since(*first) < DISK_HISTORY_MIN_SPAN {
+        return None;
+    }
+
+    let n = history.len() as f64;
+    let points: Vec<(f64, f64)> = history
+        .iter()
+        .map(|(t, used)| (t.duration_since(*first).as_secs_f64(), *used as f64))
+        .collect();
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let (num, den) = points.iter().fold((0.0, 0.0), |(num, den), (x, y)| {
+        (num + (x - mean_x) * (y - mean_y), den + (x - mean_x).powi(2))
+    });
+
+    if den > 0.0 {
+        Some(num / den)
+    } else {
+        None
+    }
+}
+
 pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
@@ -416,6 +1025,33 @@ pub fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Formats a Unix timestamp in local time the way `ps` shows start times:
+/// `HH:MM` if it was today, otherwise `Mon DD`.
+pub fn format_start_time(epoch: u64) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let local = |secs: u64| {
+        let time = secs as libc::time_t;
+        // SAFETY: tm is plain data, and both pointers outlive the call
+        let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+        unsafe { libc::localtime_r(&time, &mut tm) };
+        tm
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let (start, today) = (local(epoch), local(now));
+    if start.tm_year == today.tm_year && start.tm_yday == today.tm_yday {
+        format!("{:02}:{:02}", start.tm_hour, start.tm_min)
+    } else {
+        format!("{} {:02}", MONTHS[start.tm_mon as usize % 12], start.tm_mday)
+    }
+}
+
 pub fn format_uptime(seconds: u64) -> String {
     let days = seconds / 86400;
     let hours = (seconds % 86400) / 3600;
@@ -0,0 +1,342 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::process::Command;
+
+const RTF_GATEWAY: u32 = 0x0002;
+const RTF_REJECT: u32 = 0x0200;
+const RTF_LOCAL: u32 = 0x8000_0000;
+
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub destination: String,
+    pub gateway: Option<String>,
+    pub interface: String,
+    pub metric: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Neighbor {
+    pub address: String,
+    pub mac: Option<String>,
+    pub interface: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyRule {
+    pub priority: u32,
+    pub selector: String,
+    pub table: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NetConfig {
+    pub routes_v4: Vec<Route>,
+    pub routes_v6: Vec<Route>,
+    pub neighbors: Vec<Neighbor>,
+    pub rules: Vec<PolicyRule>,
+    pub nameservers: Vec<String>,
+    pub search: Vec<String>,
+    pub resolver_options: Vec<String>,
+    pub hosts: Vec<(String, String)>,
+}
+
+pub fn read() -> NetConfig {
+    let resolv = std::fs::read_to_string("/etc/resolv.conf").unwrap_or_default();
+    let mut config = NetConfig {
+        routes_v4: std::fs::read_to_string("/proc/net/route")
+            .map(|c| parse_routes_v4(&c))
+            .unwrap_or_default(),
+        routes_v6: std::fs::read_to_string("/proc/net/ipv6_route")
+            .map(|c| parse_routes_v6(&c))
+            .unwrap_or_default(),
+        neighbors: read_neighbors(),
+        rules: read_rules(),
+        hosts: std::fs::read_to_string("/etc/hosts")
+            .map(|c| parse_hosts(&c))
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+
+    for line in resolv.lines().map(str::trim) {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("nameserver") => config.nameservers.extend(tokens.map(String::from)),
+            Some("search") | Some("domain") => config.search.extend(tokens.map(String::from)),
+            Some("options") => config.resolver_options.extend(tokens.map(String::from)),
+            _ => {}
+        }
+    }
+
+    config
+}
+
+fn parse_routes_v4(content: &str) -> Vec<Route> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 8 {
+                return None;
+            }
+            // Addresses are host-order integers, like /proc/net/tcp
+            let addr = |hex: &str| u32::from_str_radix(hex, 16).ok().map(|v| Ipv4Addr::from(v.to_ne_bytes()));
+            let dest = addr(fields[1])?;
+            let gateway = addr(fields[2])?;
+            let flags = u32::from_str_radix(fields[3], 16).ok()?;
+            let mask = addr(fields[7])?;
+            let prefix = u32::from(mask).count_ones();
+
+            Some(Route {
+                destination: if prefix == 0 {
+                    "default".to_string()
+                } else {
+                    format!("{}/{}", dest, prefix)
+                },
+                gateway: (flags & RTF_GATEWAY != 0).then(|| gateway.to_string()),
+                interface: fields[0].to_string(),
+                metric: fields[6].parse().unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+fn parse_routes_v6(content: &str) -> Vec<Route> {
+    // Unlike the IPv4 table, these addresses are printed in network order
+    let addr = |hex: &str| -> Option<Ipv6Addr> {
+        let bytes: Vec<u8> = (0..16)
+            .map(|i| u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok())
+            .collect::<Option<_>>()?;
+        let bytes: [u8; 16] = bytes.try_into().ok()?;
+        Some(Ipv6Addr::from(bytes))
+    };
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let flags = u32::from_str_radix(fields[8], 16).ok()?;
+            let dest = addr(fields[0])?;
+            // Skip the local table and multicast, like `ip -6 route` does
+            if flags & (RTF_LOCAL | RTF_REJECT) != 0 || dest.is_multicast() {
+                return None;
+            }
+            let prefix = u8::from_str_radix(fields[1], 16).ok()?;
+            let next_hop = addr(fields[4])?;
+
+            Some(Route {
+                destination: if prefix == 0 {
+                    "default".to_string()
+                } else {
+                    format!("{}/{}", dest, prefix)
+                },
+                gateway: (!next_hop.is_unspecified()).then(|| next_hop.to_string()),
+                interface: fields[9].to_string(),
+                metric: u32::from_str_radix(fields[5], 16).unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// Neighbors for both families via `ip -j neigh`, falling back to the
+/// IPv4-only `/proc/net/arp`.
+fn read_neighbors() -> Vec<Neighbor> {
+    if let Some(neighbors) = ip_json(&["neigh", "show"]).and_then(|json| parse_ip_neigh(&json)) {
+        return neighbors;
+    }
+
+    std::fs::read_to_string("/proc/net/arp")
+        .map(|content| parse_arp(&content))
+        .unwrap_or_default()
+}
+
+/// Parses `ip -j neigh show`.
+fn parse_ip_neigh(json_str: &str) -> Option<Vec<Neighbor>> {
+    let json = serde_json::from_str::<serde_json::Value>(json_str).ok()?;
+    let entries = json.as_array()?;
+
+    Some(
+        entries
+            .iter()
+            .map(|e| Neighbor {
+                address: e["dst"].as_str().unwrap_or("-").to_string(),
+                mac: e["lladdr"].as_str().map(String::from),
+                interface: e["dev"].as_str().unwrap_or("-").to_string(),
+                state: e["state"]
+                    .as_array()
+                    .map(|s| {
+                        s.iter()
+                            .filter_map(|v| v.as_str())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+fn parse_arp(content: &str) -> Vec<Neighbor> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 {
+                return None;
+            }
+            // ATF_COM (0x2) means the entry is complete
+            let complete = u32::from_str_radix(fields[2].trim_start_matches("0x"), 16)
+                .is_ok_and(|f| f & 0x2 != 0);
+            Some(Neighbor {
+                address: fields[0].to_string(),
+                mac: Some(fields[3].to_string()),
+                interface: fields[5].to_string(),
+                state: if complete { "REACHABLE" } else { "INCOMPLETE" }.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Policy routing rules for both families, if iproute2 is installed.
+fn read_rules() -> Vec<PolicyRule> {
+    ["-4", "-6"]
+        .into_iter()
+        .filter_map(|family| ip_json(&[family, "rule", "show"]).map(|json| parse_ip_rules(&json, family)))
+        .flatten()
+        .collect()
+}
+
+/// Parses `ip -j rule show` for the given `-4` or `-6` family.
+fn parse_ip_rules(json_str: &str, family: &str) -> Vec<PolicyRule> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(json_str) else {
+        return Vec::new();
+    };
+
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|rule| {
+            let mut selector = format!("from {}", rule["src"].as_str().unwrap_or("all"));
+            if let Some(prefix) = rule["srclen"].as_u64() {
+                selector.push_str(&format!("/{}", prefix));
+            }
+            if let Some(dst) = rule["dst"].as_str() {
+                selector.push_str(&format!(" to {}", dst));
+            }
+            if let Some(iif) = rule["iif"].as_str() {
+                selector.push_str(&format!(" iif {}", iif));
+            }
+            if let Some(fwmark) = rule["fwmark"].as_str() {
+                selector.push_str(&format!(" fwmark {}", fwmark));
+            }
+
+            PolicyRule {
+                priority: rule["priority"].as_u64().unwrap_or(0) as u32,
+                selector: format!("{} {}", if family == "-4" { "v4" } else { "v6" }, selector),
+                table: rule["table"]
+                    .as_str()
+                    .map(String::from)
+                    .or_else(|| rule["action"].as_str().map(String::from))
+                    .unwrap_or_else(|| "-".to_string()),
+            }
+        })
+        .collect()
+}
+
+fn ip_json(args: &[&str]) -> Option<String> {
+    let output = Command::new("ip").arg("-j").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn parse_hosts(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter_map(|line| {
+            let (addr, names) = line.split_once(char::is_whitespace)?;
+            Some((addr.to_string(), names.split_whitespace().collect::<Vec<_>>().join(" ")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ipv6_routes() {
+        let routes = parse_routes_v6(include_str!("fixtures/proc_net_ipv6_route.txt"));
+        // The local table, multicast and the reject route are skipped
+        assert_eq!(
+            routes
+                .iter()
+                .map(|r| (r.destination.as_str(), r.gateway.as_deref(), r.interface.as_str(), r.metric))
+                .collect::<Vec<_>>(),
+            vec![
+                ("2001:db8:0:1234::/64", None, "wlan0", 256),
+                ("fe80::/64", None, "wlan0", 256),
+                ("default", Some("fe80::a691:b1ff:fe3c:7e02"), "wlan0", 600),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_ip_neigh() {
+        let neighbors = parse_ip_neigh(include_str!("fixtures/ip_neigh.json")).unwrap();
+        assert_eq!(
+            neighbors
+                .iter()
+                .map(|n| (n.address.as_str(), n.mac.as_deref(), n.interface.as_str(), n.state.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("192.168.1.1", Some("a4:91:b1:3c:7e:02"), "wlan0", "REACHABLE"),
+                ("192.168.1.42", None, "wlan0", "FAILED"),
+                ("fe80::a691:b1ff:fe3c:7e02", Some("a4:91:b1:3c:7e:02"), "wlan0", "STALE"),
+                ("10.8.0.1", Some("52:54:00:12:34:56"), "tun0", "PERMANENT,NOARP"),
+            ]
+        );
+        assert!(parse_ip_neigh("not json").is_none());
+        assert!(parse_ip_neigh(r#"{"dst": "10.0.0.1"}"#).is_none());
+    }
+
+    #[test]
+    fn parses_arp_fallback() {
+        let neighbors = parse_arp(
+            "IP address       HW type     Flags       HW address            Mask     Device\n\
+             192.168.1.1      0x1         0x2         a4:91:b1:3c:7e:02     *        wlan0\n\
+             192.168.1.42     0x1         0x0         00:00:00:00:00:00     *        wlan0\n",
+        );
+        assert_eq!(
+            neighbors.iter().map(|n| (n.address.as_str(), n.state.as_str())).collect::<Vec<_>>(),
+            vec![("192.168.1.1", "REACHABLE"), ("192.168.1.42", "INCOMPLETE")]
+        );
+    }
+
+    #[test]
+    fn parses_ip_rules() {
+        let rules = parse_ip_rules(include_str!("fixtures/ip_rule.json"), "-4");
+        assert_eq!(
+            rules
+                .iter()
+                .map(|r| (r.priority, r.selector.as_str(), r.table.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, "v4 from all", "local"),
+                (100, "v4 from 10.8.0.0/24", "vpn"),
+                (200, "v4 from all fwmark 0xca6c", "51820"),
+                (300, "v4 from all to 192.168.50.0 iif br0", "prohibit"),
+                (32766, "v4 from all", "main"),
+                (32767, "v4 from all", "default"),
+            ]
+        );
+        assert_eq!(parse_ip_rules(r#"[{"priority":0,"src":"all","table":"local"}]"#, "-6")[0].selector, "v6 from all");
+        assert!(parse_ip_rules("", "-4").is_empty());
+    }
+}
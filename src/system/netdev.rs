@@ -0,0 +1,374 @@
+use super::read_trimmed;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+const SYS_NET: &str = "/sys/class/net";
+
+/// Protocol counters worth watching, as (section, field, label) from
+/// `/proc/net/snmp` and `/proc/net/netstat`.
+const PROTOCOL_COUNTERS: [(&str, &str, &str); 11] = [
+    ("Tcp", "RetransSegs", "TCP retransmits"),
+    ("TcpExt", "TCPTimeouts", "TCP timeouts"),
+    ("Tcp", "InErrs", "TCP bad segments"),
+    ("Tcp", "OutRsts", "TCP resets sent"),
+    ("Tcp", "AttemptFails", "TCP failed connects"),
+    ("TcpExt", "ListenOverflows", "Listen overflows"),
+    ("TcpExt", "ListenDrops", "Listen drops"),
+    ("Udp", "InErrors", "UDP receive errors"),
+    ("Udp", "RcvbufErrors", "UDP rcvbuf errors"),
+    ("Udp", "SndbufErrors", "UDP sndbuf errors"),
+    ("Udp", "NoPorts", "UDP no port"),
+];
+
+/// A monotonically increasing kernel counter and its rate since the last
+/// refresh.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Counter {
+    pub total: u64,
+    pub rate: f64,
+}
+
+impl Counter {
+    fn new(total: u64, previous: Option<Counter>, elapsed: f64) -> Self {
+        let rate = match previous {
+            Some(prev) if elapsed > 0.0 => total.saturating_sub(prev.total) as f64 / elapsed,
+            _ => 0.0,
+        };
+        Self { total, rate }
+    }
+}
+
+/// Per-interface error counters from `/sys/class/net/<if>/statistics`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkErrors {
+    pub rx_errors: Counter,
+    pub tx_errors: Counter,
+    pub rx_dropped: Counter,
+    pub tx_dropped: Counter,
+    pub rx_fifo: Counter,
+    pub tx_fifo: Counter,
+    pub collisions: Counter,
+}
+
+impl LinkErrors {
+    pub fn read(name: &str, previous: Option<&LinkErrors>, elapsed: f64) -> Self {
+        let stats = Path::new(SYS_NET).join(name).join("statistics");
+        let counter = |file: &str, prev: Option<Counter>| {
+            let total = read_trimmed(&stats.join(file))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(0);
+            Counter::new(total, prev, elapsed)
+        };
+
+        Self {
+            rx_errors: counter("rx_errors", previous.map(|p| p.rx_errors)),
+            tx_errors: counter("tx_errors", previous.map(|p| p.tx_errors)),
+            rx_dropped: counter("rx_dropped", previous.map(|p| p.rx_dropped)),
+            tx_dropped: counter("tx_dropped", previous.map(|p| p.tx_dropped)),
+            rx_fifo: counter("rx_fifo_errors", previous.map(|p| p.rx_fifo)),
+            tx_fifo: counter("tx_fifo_errors", previous.map(|p| p.tx_fifo)),
+            collisions: counter("collisions", previous.map(|p| p.collisions)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProtocolCounter {
+    pub label: &'static str,
+    pub counter: Counter,
+}
+
+/// Reads TCP/UDP error counters, computing rates against `previous`.
+pub fn read_protocol_counters(previous: &[ProtocolCounter], elapsed: f64) -> Vec<ProtocolCounter> {
+    let mut values = HashMap::new();
+    for path in ["/proc/net/snmp", "/proc/net/netstat"] {
+        if let Ok(content) = std::fs::read_to_string(path) {
+            values.extend(parse_snmp(&content));
+        }
+    }
+
+    PROTOCOL_COUNTERS
+        .iter()
+        .filter_map(|(section, field, label)| {
+            let total = *values.get(&format!("{}.{}", section, field))?;
+            let prev = previous.iter().find(|p| p.label == *label).map(|p| p.counter);
+            Some(ProtocolCounter {
+                label,
+                counter: Counter::new(total, prev, elapsed),
+            })
+        })
+        .collect()
+}
+
+/// Parses the header/value line pairs of `/proc/net/snmp` and
+/// `/proc/net/netstat` into `Section.Field` keys.
+fn parse_snmp(content: &str) -> HashMap<String, u64> {
+    let mut values = HashMap::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for pair in lines.chunks(2) {
+        let [header, data] = pair else {
+            continue;
+        };
+        let (Some((section, names)), Some((_, numbers))) = (header.split_once(':'), data.split_once(':')) else {
+            continue;
+        };
+        for (name, value) in names.split_whitespace().zip(numbers.split_whitespace()) {
+            if let Ok(value) = value.parse::<u64>() {
+                values.insert(format!("{}.{}", section, name), value);
+            }
+        }
+    }
+
+    values
+}
+
+/// Link-level details for a network interface, read from sysfs.
+#[derive(Debug, Clone, Default)]
+pub struct LinkInfo {
+    pub kind: String,
+    pub mac: Option<String>,
+    pub mtu: Option<u32>,
+    pub operstate: String,
+    pub carrier: Option<bool>,
+    /// Negotiated speed in Mb/s
+    pub speed: Option<u32>,
+    pub duplex: Option<String>,
+    pub driver: Option<String>,
+    pub ipv4: Vec<String>,
+    pub ipv6: Vec<String>,
+}
+
+pub fn read_link_info(name: &str) -> LinkInfo {
+    let dev = Path::new(SYS_NET).join(name);
+
+    LinkInfo {
+        kind: interface_kind(&dev, name).to_string(),
+        mac: read_trimmed(&dev.join("address")).filter(|mac| mac != "00:00:00:00:00:00"),
+        mtu: read_trimmed(&dev.join("mtu")).and_then(|s| s.parse().ok()),
+        operstate: read_trimmed(&dev.join("operstate")).unwrap_or_else(|| "unknown".to_string()),
+        // Reading carrier fails with EINVAL while the interface is down
+        carrier: read_trimmed(&dev.join("carrier")).map(|s| s == "1"),
+        // Virtual devices report -1 or fail to read
+        speed: read_trimmed(&dev.join("speed"))
+            .and_then(|s| s.parse::<i64>().ok())
+            .filter(|&s| s > 0)
+            .map(|s| s as u32),
+        duplex: read_trimmed(&dev.join("duplex")).filter(|d| d != "unknown"),
+        driver: std::fs::read_link(dev.join("device/driver"))
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string())),
+        ipv4: Vec::new(),
+        ipv6: Vec::new(),
+    }
+}
+
+fn interface_kind(dev: &Path, name: &str) -> &'static str {
+    let devtype = read_trimmed(&dev.join("uevent"))
+        .and_then(|uevent| {
+            uevent
+                .lines()
+                .find_map(|l| l.strip_prefix("DEVTYPE=").map(|s| s.to_string()))
+        })
+        .unwrap_or_default();
+
+    // ARPHRD_LOOPBACK
+    if read_trimmed(&dev.join("type")).as_deref() == Some("772") {
+        return "loopback";
+    }
+
+    match devtype.as_str() {
+        "bridge" => return "bridge",
+        "bond" => return "bond",
+        "vlan" => return "vlan",
+        "wlan" => return "wireless",
+        "wireguard" => return "wireguard",
+        _ => {}
+    }
+
+    if dev.join("bridge").exists() {
+        "bridge"
+    } else if dev.join("bonding").exists() {
+        "bond"
+    } else if Path::new("/proc/net/vlan").join(name).exists() {
+        "vlan"
+    } else if dev.join("tun_flags").exists() {
+        "tun"
+    } else if dev.join("wireless").exists() || dev.join("phy80211").exists() {
+        "wireless"
+    } else if dev.join("device").exists() {
+        "physical"
+    } else if read_trimmed(&dev.join("iflink")) != read_trimmed(&dev.join("ifindex")) {
+        // A virtual device linked to a peer interface
+        "veth"
+    } else {
+        "virtual"
+    }
+}
+
+/// Radio link details for a wireless interface.
+#[derive(Debug, Clone, Default)]
+pub struct WirelessInfo {
+    /// Link quality as reported by the driver, out of `QUALITY_MAX`
+    pub quality: f32,
+    pub signal_dbm: Option<f32>,
+    pub noise_dbm: Option<f32>,
+    pub ssid: Option<String>,
+    /// Frequency in MHz
+    pub frequency: Option<u32>,
+    /// Transmit bitrate in Mb/s
+    pub bitrate: Option<f32>,
+}
+
+impl WirelessInfo {
+    /// The scale most drivers use for the link quality column.
+    pub const QUALITY_MAX: f32 = 70.0;
+}
+
+/// Reads signal level and link quality of wireless interfaces from
+/// `/proc/net/wireless`.
+pub fn read_wireless() -> HashMap<String, WirelessInfo> {
+    std::fs::read_to_string("/proc/net/wireless")
+        .map(|content| parse_proc_wireless(&content))
+        .unwrap_or_default()
+}
+
+/// Asks `iw` for the SSID, frequency and bitrate of a wireless link,
+/// which `/proc/net/wireless` does not carry.
+pub fn read_iw_link(name: &str, info: &mut WirelessInfo) {
+    info.ssid = None;
+    info.frequency = None;
+    info.bitrate = None;
+
+    let Ok(output) = Command::new("iw").args(["dev", name, "link"]).output() else {
+        return;
+    };
+    if output.status.success() {
+        parse_iw_link(&String::from_utf8_lossy(&output.stdout), info);
+    }
+}
+
+fn parse_proc_wireless(content: &str) -> HashMap<String, WirelessInfo> {
+    // Two header lines, then "wlan0: 0000   54.  -56.  -256  ..."
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let fields: Vec<f32> = rest
+                .split_whitespace()
+                .take(4)
+                .map(|f| f.trim_end_matches('.').parse().ok())
+                .collect::<Option<_>>()?;
+            let [_, quality, signal, noise] = fields[..] else {
+                return None;
+            };
+            // Levels are in dBm; drivers without a reading report -256
+            let level = |v: f32| (v < 0.0 && v > -256.0).then_some(v);
+
+            Some((
+                name.trim().to_string(),
+                WirelessInfo {
+                    quality,
+                    signal_dbm: level(signal),
+                    noise_dbm: level(noise),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect()
+}
+
+fn parse_iw_link(output: &str, info: &mut WirelessInfo) {
+    for line in output.lines().map(str::trim) {
+        if let Some(ssid) = line.strip_prefix("SSID: ") {
+            info.ssid = Some(ssid.to_string());
+        } else if let Some(freq) = line.strip_prefix("freq: ") {
+            // Newer iw prints fractional MHz, e.g. "5180.0"
+            info.frequency = freq.split('.').next().and_then(|f| f.parse().ok());
+        } else if let Some(rate) = line.strip_prefix("tx bitrate: ") {
+            info.bitrate = rate.split_whitespace().next().and_then(|r| r.parse().ok());
+        } else if let Some(signal) = line.strip_prefix("signal: ") {
+            if info.signal_dbm.is_none() {
+                info.signal_dbm = signal.split_whitespace().next().and_then(|s| s.parse().ok());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_snmp_counters() {
+        let values = parse_snmp(include_str!("fixtures/proc_net_snmp.txt"));
+        assert_eq!(values.get("Tcp.RetransSegs"), Some(&15832));
+        assert_eq!(values.get("Tcp.OutRsts"), Some(&9120));
+        assert_eq!(values.get("Udp.NoPorts"), Some(&1385));
+        assert_eq!(values.get("Udp.RcvbufErrors"), Some(&57));
+        assert_eq!(values.get("IcmpMsg.InType3"), Some(&398));
+        // Fields of the same name stay apart per section
+        assert_eq!(values.get("Udp.InErrors"), Some(&57));
+        assert_eq!(values.get("Icmp.InErrors"), Some(&3));
+        // MaxConn is -1, which isn't a counter
+        assert_eq!(values.get("Tcp.MaxConn"), None);
+    }
+
+    #[test]
+    fn parses_netstat_counters() {
+        let values = parse_snmp(include_str!("fixtures/proc_net_netstat.txt"));
+        assert_eq!(values.get("TcpExt.TCPTimeouts"), Some(&3104));
+        assert_eq!(values.get("TcpExt.ListenOverflows"), Some(&6));
+        assert_eq!(values.get("IpExt.InOctets"), Some(&11288123304));
+    }
+
+    #[test]
+    fn ignores_unpaired_lines() {
+        assert!(parse_snmp("").is_empty());
+        assert!(parse_snmp("Tcp: RetransSegs InErrs\n").is_empty());
+        assert!(parse_snmp("Tcp RetransSegs\nTcp 5\n").is_empty());
+    }
+
+    #[test]
+    fn parses_proc_wireless() {
+        let interfaces = parse_proc_wireless(include_str!("fixtures/proc_net_wireless.txt"));
+        assert_eq!(interfaces.len(), 2);
+
+        let wlan0 = &interfaces["wlan0"];
+        assert_eq!(wlan0.quality, 54.0);
+        assert_eq!(wlan0.signal_dbm, Some(-56.0));
+        // -256 means the driver has no reading
+        assert_eq!(wlan0.noise_dbm, None);
+        assert_eq!(wlan0.ssid, None);
+
+        let idle = &interfaces["wlp2s0"];
+        assert_eq!(idle.quality, 0.0);
+        assert_eq!((idle.signal_dbm, idle.noise_dbm), (None, None));
+
+        assert!(parse_proc_wireless("").is_empty());
+    }
+
+    #[test]
+    fn parses_iw_link() {
+        let mut info = WirelessInfo {
+            signal_dbm: Some(-60.0),
+            ..Default::default()
+        };
+        parse_iw_link(include_str!("fixtures/iw_link.txt"), &mut info);
+        assert_eq!(info.ssid.as_deref(), Some("Home Network 5G"));
+        assert_eq!(info.frequency, Some(5180));
+        assert_eq!(info.bitrate, Some(585.1));
+        // The /proc/net/wireless reading wins over iw's
+        assert_eq!(info.signal_dbm, Some(-60.0));
+
+        let mut info = WirelessInfo::default();
+        parse_iw_link(include_str!("fixtures/iw_link.txt"), &mut info);
+        assert_eq!(info.signal_dbm, Some(-56.0));
+
+        let mut info = WirelessInfo::default();
+        parse_iw_link("Not connected.\n", &mut info);
+        assert_eq!((info.ssid, info.frequency, info.bitrate), (None, None, None));
+    }
+}
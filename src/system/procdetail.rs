@@ -0,0 +1,364 @@
+use super::threads::ProcessThreads;
+use super::{ProcessInfo, ProcessKey};
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::path::Path;
+
+/// Number of CPU/memory samples kept for the detail view charts.
+const DETAIL_HISTORY_LEN: usize = 120;
+
+/// Everything /proc has to say about one process, for the Process Detail
+/// view. Sections that need more privileges than we have hold the error.
+#[derive(Debug, Clone)]
+pub struct ProcessDetail {
+    pub key: ProcessKey,
+    pub name: String,
+    /// Set once the process is gone; the last data read is kept
+    pub exited: bool,
+    pub status: Vec<(String, String)>,
+    pub cmdline: Vec<String>,
+    pub cwd: Result<String, String>,
+    pub exe: Result<String, String>,
+    pub environ: Result<Vec<String>, String>,
+    pub limits: Vec<Limit>,
+    pub fds: Result<Vec<OpenFile>, String>,
+    pub maps: Result<MemoryMaps, String>,
+    pub cgroups: Vec<String>,
+    pub namespaces: Vec<(String, String)>,
+    pub threads: ProcessThreads,
+    /// CPU usage in tenths of a percent and resident memory in bytes,
+    /// oldest first
+    pub cpu_history: VecDeque<u64>,
+    pub memory_history: VecDeque<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Limit {
+    pub name: String,
+    pub soft: String,
+    pub hard: String,
+    pub units: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenFile {
+    pub fd: u32,
+    pub target: String,
+}
+
+/// Summary of `/proc/<pid>/maps` and `smaps_rollup`.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryMaps {
+    pub regions: usize,
+    pub total: u64,
+    /// Region count and mapped size per kind: file, anonymous, heap, stack, other
+    pub kinds: Vec<(&'static str, usize, u64)>,
+    /// Mapped files with the most address space, largest first
+    pub largest_files: Vec<(String, u64)>,
+    /// Rss, Pss, Swap and friends in bytes, if smaps_rollup is readable
+    pub rollup: Vec<(String, u64)>,
+}
+
+impl ProcessDetail {
+    pub fn new(proc: &ProcessInfo) -> Self {
+        let mut detail = Self {
+            key: proc.key(),
+            name: proc.name.clone(),
+            exited: false,
+            status: Vec::new(),
+            cmdline: Vec::new(),
+            cwd: Err(String::new()),
+            exe: Err(String::new()),
+            environ: Ok(Vec::new()),
+            limits: Vec::new(),
+            fds: Ok(Vec::new()),
+            maps: Ok(MemoryMaps::default()),
+            cgroups: Vec::new(),
+            namespaces: Vec::new(),
+            threads: ProcessThreads::default(),
+            cpu_history: VecDeque::new(),
+            memory_history: VecDeque::new(),
+        };
+        detail.read_proc();
+        detail.push_sample(proc);
+        detail
+    }
+
+    /// Re-reads /proc and records a chart sample from the freshly
+    /// refreshed process list.
+    pub fn refresh(&mut self, processes: &[ProcessInfo]) {
+        if self.exited {
+            return;
+        }
+        // The PID may already belong to something else
+        if self.key.verify().is_err() {
+            self.exited = true;
+            return;
+        }
+
+        self.read_proc();
+        if let Some(proc) = processes.iter().find(|p| p.key() == self.key) {
+            self.push_sample(proc);
+        }
+    }
+
+    fn push_sample(&mut self, proc: &ProcessInfo) {
+        self.cpu_history.push_back((proc.cpu_usage * 10.0) as u64);
+        self.memory_history.push_back(proc.memory);
+        if self.cpu_history.len() > DETAIL_HISTORY_LEN {
+            self.cpu_history.pop_front();
+            self.memory_history.pop_front();
+        }
+    }
+
+    fn read_proc(&mut self) {
+        let dir = Path::new("/proc").join(self.key.pid.to_string());
+
+        self.status = std::fs::read_to_string(dir.join("status"))
+            .map(|content| parse_status(&content))
+            .unwrap_or_default();
+        self.cmdline = std::fs::read(dir.join("cmdline"))
+            .map(|bytes| split_nul(&bytes))
+            .unwrap_or_default();
+        self.cwd = read_link(&dir.join("cwd"));
+        self.exe = read_link(&dir.join("exe"));
+        self.environ = std::fs::read(dir.join("environ"))
+            .map(|bytes| {
+                let mut vars = split_nul(&bytes);
+                vars.sort();
+                vars
+            })
+            .map_err(describe);
+        self.limits = std::fs::read_to_string(dir.join("limits"))
+            .map(|content| parse_limits(&content))
+            .unwrap_or_default();
+        self.fds = read_fds(&dir.join("fd"));
+        self.maps = std::fs::read_to_string(dir.join("maps"))
+            .map(|content| {
+                let mut maps = parse_maps(&content);
+                if let Ok(rollup) = std::fs::read_to_string(dir.join("smaps_rollup")) {
+                    maps.rollup = parse_rollup(&rollup);
+                }
+                maps
+            })
+            .map_err(describe);
+        self.cgroups = std::fs::read_to_string(dir.join("cgroup"))
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        self.namespaces = read_namespaces(&dir.join("ns"));
+        self.threads.refresh(self.key.pid);
+    }
+}
+
+fn parse_status(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| {
+            // Collapse the tab-separated groups, e.g. the four Uid values
+            (key.to_string(), value.split_whitespace().collect::<Vec<_>>().join(" "))
+        })
+        .collect()
+}
+
+fn split_nul(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .split(|b| *b == 0)
+        .filter(|s| !s.is_empty())
+        .map(|s| String::from_utf8_lossy(s).to_string())
+        .collect()
+}
+
+/// `/proc/<pid>/limits` is a fixed-width table; the header tells us where
+/// each column starts.
+fn parse_limits(content: &str) -> Vec<Limit> {
+    let mut lines = content.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let (Some(soft), Some(hard), Some(units)) =
+        (header.find("Soft Limit"), header.find("Hard Limit"), header.find("Units"))
+    else {
+        return Vec::new();
+    };
+
+    let column = |line: &str, start: usize, end: usize| {
+        line.get(start..end.min(line.len())).unwrap_or("").trim().to_string()
+    };
+    lines
+        .map(|line| Limit {
+            name: column(line, 0, soft),
+            soft: column(line, soft, hard),
+            hard: column(line, hard, units),
+            units: column(line, units, line.len()),
+        })
+        .collect()
+}
+
+fn parse_maps(content: &str) -> MemoryMaps {
+    let mut maps = MemoryMaps::default();
+    let mut kinds: Vec<(&'static str, usize, u64)> =
+        ["file", "anonymous", "heap", "stack", "other"].into_iter().map(|k| (k, 0, 0)).collect();
+    let mut files: HashMap<String, u64> = HashMap::new();
+
+    for line in content.lines() {
+        // address perms offset dev inode [path]; the path is padded and may
+        // contain spaces
+        let mut parts = line.splitn(6, ' ');
+        let Some((start, end)) = parts.next().and_then(|range| range.split_once('-')) else {
+            continue;
+        };
+        let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16)) else {
+            continue;
+        };
+        let size = end.saturating_sub(start);
+        let path = parts.nth(4).map(str::trim).unwrap_or("");
+
+        let kind = match path {
+            "" => 1,
+            "[heap]" => 2,
+            p if p.starts_with("[stack") => 3,
+            p if p.starts_with('[') => 4,
+            _ => {
+                *files.entry(path.to_string()).or_default() += size;
+                0
+            }
+        };
+        kinds[kind].1 += 1;
+        kinds[kind].2 += size;
+        maps.regions += 1;
+        maps.total += size;
+    }
+
+    maps.kinds = kinds.into_iter().filter(|(_, count, _)| *count > 0).collect();
+    maps.largest_files = files.into_iter().collect();
+    maps.largest_files.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    maps.largest_files.truncate(10);
+    maps
+}
+
+fn parse_rollup(content: &str) -> Vec<(String, u64)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let kb: u64 = value.trim().strip_suffix("kB")?.trim().parse().ok()?;
+            Some((key.to_string(), kb * 1024))
+        })
+        .collect()
+}
+
+fn read_fds(dir: &Path) -> Result<Vec<OpenFile>, String> {
+    let mut fds: Vec<OpenFile> = std::fs::read_dir(dir)
+        .map_err(describe)?
+        .flatten()
+        .filter_map(|entry| {
+            let fd = entry.file_name().to_str()?.parse().ok()?;
+            // The fd may close between listing and reading the link
+            let target = read_link(&entry.path()).unwrap_or_else(|e| format!("({})", e));
+            Some(OpenFile { fd, target })
+        })
+        .collect();
+    fds.sort_by_key(|f| f.fd);
+    Ok(fds)
+}
+
+fn read_namespaces(dir: &Path) -> Vec<(String, String)> {
+    let mut namespaces: Vec<(String, String)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_str()?.to_string();
+            Some((name, read_link(&entry.path()).ok()?))
+        })
+        .collect();
+    namespaces.sort();
+    namespaces
+}
+
+fn read_link(path: &Path) -> Result<String, String> {
+    std::fs::read_link(path)
+        .map(|target| target.to_string_lossy().to_string())
+        .map_err(describe)
+}
+
+fn describe(err: io::Error) -> String {
+    match err.kind() {
+        io::ErrorKind::PermissionDenied => "permission denied (try running as root)".to_string(),
+        io::ErrorKind::NotFound => "not available (the process may have exited)".to_string(),
+        _ => err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_status() {
+        let status = parse_status(include_str!("fixtures/proc_pid_status.txt"));
+        let get = |key: &str| status.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+        assert_eq!(status[0], ("Name".to_string(), "postgres".to_string()));
+        assert_eq!(get("State"), Some("S (sleeping)"));
+        assert_eq!(get("Uid"), Some("113 113 113 113"));
+        assert_eq!(get("Groups"), Some("108 121"));
+        assert_eq!(get("VmRSS"), Some("28416 kB"));
+        assert_eq!(get("nonvoluntary_ctxt_switches"), Some("41"));
+        assert_eq!(status.len(), 20);
+    }
+
+    #[test]
+    fn parses_limits() {
+        let limits = parse_limits(include_str!("fixtures/proc_pid_limits.txt"));
+        assert_eq!(limits.len(), 16);
+
+        let open_files = limits.iter().find(|l| l.name == "Max open files").unwrap();
+        assert_eq!(
+            (open_files.soft.as_str(), open_files.hard.as_str(), open_files.units.as_str()),
+            ("1024", "524288", "files")
+        );
+        assert_eq!(limits[0].soft, "unlimited");
+        assert_eq!(limits[0].units, "seconds");
+        // Priorities have no units
+        let nice = limits.iter().find(|l| l.name == "Max nice priority").unwrap();
+        assert_eq!((nice.soft.as_str(), nice.hard.as_str(), nice.units.as_str()), ("0", "0", ""));
+
+        assert!(parse_limits("").is_empty());
+        assert!(parse_limits("not a limits table\n").is_empty());
+    }
+
+    #[test]
+    fn summarizes_maps() {
+        let maps = parse_maps(include_str!("fixtures/proc_pid_maps.txt"));
+        assert_eq!(maps.regions, 9);
+        assert_eq!(maps.total, 9445376 + 53248 + 528384 + 135168 + 8192);
+        assert_eq!(
+            maps.kinds,
+            vec![
+                ("file", 5, 9445376),
+                ("anonymous", 1, 53248),
+                ("heap", 1, 528384),
+                ("stack", 1, 135168),
+                ("other", 1, 8192),
+            ]
+        );
+        assert_eq!(
+            maps.largest_files,
+            vec![
+                ("/dev/shm/PostgreSQL.1214 (deleted)".to_string(), 4194304),
+                ("/usr/lib/postgresql/16/bin/postgres".to_string(), 3428352),
+                ("/usr/lib/x86_64-linux-gnu/libc.so.6".to_string(), 1822720),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_smaps_rollup() {
+        let rollup = parse_rollup(include_str!("fixtures/proc_pid_smaps_rollup.txt"));
+        assert_eq!(rollup.len(), 9);
+        assert_eq!(rollup[0], ("Rss".to_string(), 28416 * 1024));
+        assert_eq!(rollup[1], ("Pss".to_string(), 11203 * 1024));
+        assert_eq!(rollup.iter().find(|(k, _)| k == "Swap").map(|(_, v)| *v), Some(0));
+    }
+}
@@ -0,0 +1,232 @@
+use super::ProcessInfo;
+
+/// A parsed Processes filter query. Terms are separated by whitespace and
+/// must all match:
+///
+/// - `user:postgres`, `state:zombie`, `pid:1234`
+/// - `cpu>10`, `mem>1G` (also `<`, `>=`, `<=`, `=`)
+/// - anything else is a case-insensitive substring of the name or command line
+#[derive(Debug, Clone, Default)]
+pub struct ProcessFilter {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone)]
+enum Term {
+    Text(String),
+    User(String),
+    State(String),
+    Pid(u32),
+    Cpu(Comparison, f32),
+    Memory(Comparison, u64),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Less,
+    LessEqual,
+    Equal,
+    GreaterEqual,
+    Greater,
+}
+
+impl Comparison {
+    fn holds<T: PartialOrd>(self, value: T, limit: T) -> bool {
+        match self {
+            Comparison::Less => value < limit,
+            Comparison::LessEqual => value <= limit,
+            Comparison::Equal => value == limit,
+            Comparison::GreaterEqual => value >= limit,
+            Comparison::Greater => value > limit,
+        }
+    }
+}
+
+impl ProcessFilter {
+    pub fn parse(query: &str) -> Self {
+        Self {
+            terms: query.split_whitespace().map(parse_term).collect(),
+        }
+    }
+
+    pub fn matches(&self, proc: &ProcessInfo) -> bool {
+        self.terms.iter().all(|term| match term {
+            Term::Text(text) => {
+                proc.name.to_lowercase().contains(text) || proc.cmdline.to_lowercase().contains(text)
+            }
+            Term::User(user) => proc.user.to_lowercase().contains(user),
+            Term::State(state) => proc.status.to_lowercase().contains(state),
+            Term::Pid(pid) => proc.pid == *pid,
+            Term::Cpu(cmp, limit) => cmp.holds(proc.cpu_usage, *limit),
+            Term::Memory(cmp, limit) => cmp.holds(proc.memory, *limit),
+        })
+    }
+}
+
+fn parse_term(term: &str) -> Term {
+    let lower = term.to_lowercase();
+
+    if let Some((key, value)) = lower.split_once(':') {
+        let parsed = match key {
+            "user" => Some(Term::User(value.to_string())),
+            "state" | "status" => Some(Term::State(value.to_string())),
+            "pid" => value.parse().ok().map(Term::Pid),
+            _ => None,
+        };
+        if let Some(parsed) = parsed {
+            return parsed;
+        }
+    }
+
+    for key in ["cpu", "mem"] {
+        let Some((cmp, value)) = lower.strip_prefix(key).and_then(parse_comparison) else {
+            continue;
+        };
+        let parsed = if key == "cpu" {
+            value.trim_end_matches('%').parse().ok().map(|v| Term::Cpu(cmp, v))
+        } else {
+            parse_size(value).map(|v| Term::Memory(cmp, v))
+        };
+        if let Some(parsed) = parsed {
+            return parsed;
+        }
+    }
+
+    // Anything we can't make sense of is just text to search for
+    Term::Text(lower)
+}
+
+fn parse_comparison(rest: &str) -> Option<(Comparison, &str)> {
+    // Two-character operators first so ">=" isn't read as ">"
+    [
+        (">=", Comparison::GreaterEqual),
+        ("<=", Comparison::LessEqual),
+        (">", Comparison::Greater),
+        ("<", Comparison::Less),
+        ("=", Comparison::Equal),
+    ]
+    .into_iter()
+    .find_map(|(op, cmp)| rest.strip_prefix(op).map(|value| (cmp, value)))
+}
+
+/// Parses sizes like `512`, `100k`, `1.5G` or `2GiB` into bytes.
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim_end_matches("ib").trim_end_matches('b');
+    let (number, multiplier) = match value.chars().last()? {
+        'k' => (&value[..value.len() - 1], 1u64 << 10),
+        'm' => (&value[..value.len() - 1], 1 << 20),
+        'g' => (&value[..value.len() - 1], 1 << 30),
+        't' => (&value[..value.len() - 1], 1 << 40),
+        _ => (value, 1),
+    };
+    let number: f64 = number.parse().ok()?;
+    Some((number * multiplier as f64) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, name: &str) -> ProcessInfo {
+        let mut proc = ProcessInfo::for_test(pid, Some(1), name);
+        proc.cmdline = format!("/usr/bin/{} --config /etc/{}.conf", name, name);
+        proc.user = "postgres".to_string();
+        proc.status = "Sleeping".to_string();
+        proc.cpu_usage = 12.5;
+        proc.memory = 3 << 30;
+        proc
+    }
+
+    fn matches(query: &str, proc: &ProcessInfo) -> bool {
+        ProcessFilter::parse(query).matches(proc)
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(matches("", &process(10, "postgres")));
+        assert!(matches("   ", &process(10, "postgres")));
+    }
+
+    #[test]
+    fn text_matches_name_or_command_line_case_insensitively() {
+        let proc = process(10, "nginx");
+        assert!(matches("NGI", &proc));
+        assert!(matches("nginx.conf", &proc));
+        assert!(!matches("apache", &proc));
+    }
+
+    #[test]
+    fn matches_user_state_and_pid() {
+        let proc = process(4321, "postgres");
+        assert!(matches("user:postgres", &proc));
+        assert!(matches("user:POST", &proc));
+        assert!(!matches("user:root", &proc));
+        assert!(matches("state:sleep", &proc));
+        assert!(matches("status:sleeping", &proc));
+        assert!(!matches("state:zombie", &proc));
+        assert!(matches("pid:4321", &proc));
+        assert!(!matches("pid:432", &proc));
+    }
+
+    #[test]
+    fn compares_cpu() {
+        let proc = process(10, "busy");
+        assert!(matches("cpu>10", &proc));
+        assert!(matches("cpu>=12.5", &proc));
+        assert!(matches("cpu<=12.5", &proc));
+        assert!(matches("cpu=12.5", &proc));
+        assert!(matches("cpu<20%", &proc));
+        assert!(!matches("cpu>12.5", &proc));
+        assert!(!matches("cpu<12.5", &proc));
+    }
+
+    #[test]
+    fn compares_memory_with_size_suffixes() {
+        let proc = process(10, "big");
+        assert!(matches("mem>1G", &proc));
+        assert!(matches("mem=3g", &proc));
+        assert!(matches("mem=3GiB", &proc));
+        assert!(matches("mem=3gb", &proc));
+        assert!(matches("mem<4G", &proc));
+        assert!(matches("mem>=3072M", &proc));
+        assert!(matches("mem<=3145728k", &proc));
+        assert!(matches("mem<1t", &proc));
+        assert!(!matches("mem>3G", &proc));
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size("100k"), Some(100 << 10));
+        assert_eq!(parse_size("100kb"), Some(100 << 10));
+        assert_eq!(parse_size("1.5g"), Some(3 << 29));
+        assert_eq!(parse_size("2gib"), Some(2 << 30));
+        assert_eq!(parse_size("2mib"), Some(2 << 20));
+        assert_eq!(parse_size("1t"), Some(1 << 40));
+        assert_eq!(parse_size(""), None);
+        assert_eq!(parse_size("lots"), None);
+    }
+
+    #[test]
+    fn malformed_terms_fall_back_to_text() {
+        let proc = process(10, "postgres");
+        // None of these appear in the name or command line
+        assert!(!matches("mem>", &proc));
+        assert!(!matches("cpu>x", &proc));
+        assert!(!matches("pid:abc", &proc));
+        assert!(!matches("color:red", &proc));
+
+        let mut odd = process(11, "cpu>x");
+        odd.cmdline = "pid:abc".to_string();
+        assert!(matches("cpu>x", &odd));
+        assert!(matches("pid:abc", &odd));
+    }
+
+    #[test]
+    fn all_terms_must_match() {
+        let proc = process(10, "postgres");
+        assert!(matches("user:postgres cpu>10 mem>1G postgres", &proc));
+        assert!(!matches("user:postgres cpu>50", &proc));
+        assert!(!matches("user:root postgres", &proc));
+    }
+}
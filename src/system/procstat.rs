@@ -0,0 +1,82 @@
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Fields of `/proc/<pid>/stat` (or `/proc/<pid>/task/<tid>/stat`) that
+/// sysinfo does not expose.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcStat {
+    pub state: char,
+    pub tty_nr: u32,
+    /// User and system CPU time in clock ticks
+    pub utime: u64,
+    pub stime: u64,
+    pub priority: i64,
+    pub nice: i64,
+    pub threads: u32,
+    /// Start time in clock ticks since boot; with the PID it identifies a
+    /// process even after the PID is reused
+    pub start_ticks: u64,
+    /// CPU the task last ran on
+    pub processor: u32,
+}
+
+impl ProcStat {
+    /// Accumulated CPU time in seconds.
+    pub fn cpu_time(&self) -> f64 {
+        (self.utime + self.stime) as f64 / clock_ticks() as f64
+    }
+}
+
+pub fn read(path: &Path) -> Option<ProcStat> {
+    parse(&std::fs::read_to_string(path).ok()?)
+}
+
+pub fn parse(content: &str) -> Option<ProcStat> {
+    // The command name may contain spaces and parentheses, so split after
+    // the last ')'; fields are then numbered from 3
+    let (_, rest) = content.rsplit_once(')')?;
+    let fields: Vec<&str> = rest.split_whitespace().collect();
+    let field = |n: usize| fields.get(n - 3).copied();
+
+    Some(ProcStat {
+        state: field(3)?.chars().next()?,
+        tty_nr: field(7)?.parse().ok()?,
+        utime: field(14)?.parse().ok()?,
+        stime: field(15)?.parse().ok()?,
+        priority: field(18)?.parse().ok()?,
+        nice: field(19)?.parse().ok()?,
+        threads: field(20)?.parse().ok()?,
+        start_ticks: field(22)?.parse().ok()?,
+        processor: field(39).and_then(|f| f.parse().ok()).unwrap_or(0),
+    })
+}
+
+/// Decodes a `tty_nr` device number into a name like `pts/3` or `tty1`.
+pub fn tty_name(tty_nr: u32) -> Option<String> {
+    if tty_nr == 0 {
+        return None;
+    }
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+
+    Some(match major {
+        136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+        4 if minor < 64 => format!("tty{}", minor),
+        4 => format!("ttyS{}", minor - 64),
+        _ => format!("{}:{}", major, minor),
+    })
+}
+
+/// Kernel clock ticks per second, the unit of the stat CPU times.
+pub fn clock_ticks() -> u64 {
+    static TICKS: OnceLock<u64> = OnceLock::new();
+    *TICKS.get_or_init(|| {
+        // SAFETY: sysconf has no preconditions
+        let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+        if ticks > 0 {
+            ticks as u64
+        } else {
+            100
+        }
+    })
+}
@@ -0,0 +1,244 @@
+use super::threads::ThreadInfo;
+use super::{ProcessInfo, ProcessKey};
+use std::collections::{HashMap, HashSet};
+
+/// One row of the Processes table. In flat mode every row has depth 0 and
+/// the subtree totals equal the process's own usage.
+#[derive(Debug, Clone)]
+pub struct ProcessRow<'a> {
+    pub proc: &'a ProcessInfo,
+    /// Set for the rows of an expanded process's threads; `proc` is then
+    /// the process the thread belongs to
+    pub thread: Option<&'a ThreadInfo>,
+    pub depth: usize,
+    pub has_children: bool,
+    pub collapsed: bool,
+    /// CPU and memory of the process plus all of its descendants
+    pub subtree_cpu: f32,
+    pub subtree_memory: u64,
+}
+
+/// Rows for the processes as given, in their current order.
+pub fn flat<'a>(processes: &[&'a ProcessInfo]) -> Vec<ProcessRow<'a>> {
+    processes
+        .iter()
+        .map(|proc| ProcessRow {
+            proc,
+            thread: None,
+            depth: 0,
+            has_children: false,
+            collapsed: false,
+            subtree_cpu: proc.cpu_usage,
+            subtree_memory: proc.memory,
+        })
+        .collect()
+}
+
+/// Arranges processes by parent PID, depth first. Siblings keep their
+/// order from `processes`, so the tree follows the current sort. A process
+/// whose parent is not in the list becomes a root, as does the first
+/// process of a parent loop; the children of `collapsed` PIDs are left out
+/// but still count towards the totals.
+pub fn build<'a>(processes: &[&'a ProcessInfo], collapsed: &HashSet<u32>) -> Vec<ProcessRow<'a>> {
+    let pids: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    let mut children: HashMap<u32, Vec<&'a ProcessInfo>> = HashMap::new();
+    let mut roots = Vec::new();
+
+    for &proc in processes {
+        match proc.parent_pid.filter(|ppid| pids.contains(ppid) && *ppid != proc.pid) {
+            Some(ppid) => children.entry(ppid).or_default().push(proc),
+            None => roots.push(proc),
+        }
+    }
+
+    let mut totals = HashMap::new();
+    let mut visited = HashSet::new();
+    let mut rows = Vec::with_capacity(processes.len());
+    for root in roots {
+        subtree_totals(root, &children, &mut totals);
+        push_rows(root, 0, &children, &totals, collapsed, &mut visited, &mut rows);
+    }
+
+    // Processes whose parents form a loop (PID reuse can do that) are
+    // unreachable from any root, which the totals walk reaches all of;
+    // show each loop from its first process
+    for &proc in processes {
+        if !totals.contains_key(&proc.pid) {
+            subtree_totals(proc, &children, &mut totals);
+            push_rows(proc, 0, &children, &totals, collapsed, &mut visited, &mut rows);
+        }
+    }
+    rows
+}
+
+/// All descendants of `pid`, deepest first so children can be signalled
+/// before their parents.
+pub fn descendants(processes: &[ProcessInfo], pid: u32) -> Vec<ProcessKey> {
+    let mut result: Vec<&ProcessInfo> = Vec::new();
+    let mut frontier = vec![pid];
+
+    while let Some(parent) = frontier.pop() {
+        for child in processes.iter().filter(|p| p.parent_pid == Some(parent) && p.pid != parent) {
+            // Guard against PID reuse creating a loop back to the root
+            if child.pid != pid && !result.iter().any(|p| p.pid == child.pid) {
+                result.push(child);
+                frontier.push(child.pid);
+            }
+        }
+    }
+
+    result.iter().rev().map(|p| p.key()).collect()
+}
+
+fn subtree_totals(
+    proc: &ProcessInfo,
+    children: &HashMap<u32, Vec<&ProcessInfo>>,
+    totals: &mut HashMap<u32, (f32, u64)>,
+) -> (f32, u64) {
+    let (mut cpu, mut memory) = (proc.cpu_usage, proc.memory);
+    // Insert a placeholder first so a cycle terminates
+    totals.insert(proc.pid, (cpu, memory));
+
+    for child in children.get(&proc.pid).into_iter().flatten() {
+        if totals.contains_key(&child.pid) {
+            continue;
+        }
+        let (child_cpu, child_memory) = subtree_totals(child, children, totals);
+        cpu += child_cpu;
+        memory += child_memory;
+    }
+
+    totals.insert(proc.pid, (cpu, memory));
+    (cpu, memory)
+}
+
+fn push_rows<'a>(
+    proc: &'a ProcessInfo,
+    depth: usize,
+    children: &HashMap<u32, Vec<&'a ProcessInfo>>,
+    totals: &HashMap<u32, (f32, u64)>,
+    collapsed: &HashSet<u32>,
+    visited: &mut HashSet<u32>,
+    rows: &mut Vec<ProcessRow<'a>>,
+) {
+    if !visited.insert(proc.pid) {
+        return;
+    }
+    let kids = children.get(&proc.pid).map(Vec::as_slice).unwrap_or_default();
+    let is_collapsed = collapsed.contains(&proc.pid);
+    let (subtree_cpu, subtree_memory) = totals.get(&proc.pid).copied().unwrap_or((proc.cpu_usage, proc.memory));
+
+    rows.push(ProcessRow {
+        proc,
+        thread: None,
+        depth,
+        has_children: !kids.is_empty(),
+        collapsed: is_collapsed,
+        subtree_cpu,
+        subtree_memory,
+    });
+
+    if !is_collapsed {
+        for child in kids {
+            push_rows(child, depth + 1, children, totals, collapsed, visited, rows);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent_pid: Option<u32>, cpu_usage: f32, memory: u64) -> ProcessInfo {
+        let mut proc = ProcessInfo::for_test(pid, parent_pid, &format!("proc{}", pid));
+        proc.cpu_usage = cpu_usage;
+        proc.memory = memory;
+        proc
+    }
+
+    /// init(1) -> sshd(10) -> bash(20) -> vim(30), plus cron(11) under init
+    fn sample() -> Vec<ProcessInfo> {
+        vec![
+            process(1, Some(0), 1.0, 100),
+            process(10, Some(1), 2.0, 200),
+            process(11, Some(1), 4.0, 400),
+            process(20, Some(10), 8.0, 800),
+            process(30, Some(20), 16.0, 1600),
+        ]
+    }
+
+    fn layout(rows: &[ProcessRow]) -> Vec<(u32, usize)> {
+        rows.iter().map(|row| (row.proc.pid, row.depth)).collect()
+    }
+
+    #[test]
+    fn builds_depth_first_keeping_sibling_order() {
+        let processes = sample();
+        let refs: Vec<&ProcessInfo> = processes.iter().collect();
+        let rows = build(&refs, &HashSet::new());
+        assert_eq!(layout(&rows), vec![(1, 0), (10, 1), (20, 2), (30, 3), (11, 1)]);
+        assert!(rows[0].has_children);
+        assert!(!rows[3].has_children);
+    }
+
+    #[test]
+    fn orphans_become_roots() {
+        let processes = [process(1, None, 0.0, 0), process(50, Some(49), 0.0, 0), process(51, Some(50), 0.0, 0)];
+        let refs: Vec<&ProcessInfo> = processes.iter().collect();
+        let rows = build(&refs, &HashSet::new());
+        assert_eq!(layout(&rows), vec![(1, 0), (50, 0), (51, 1)]);
+    }
+
+    #[test]
+    fn parent_loops_are_shown_once() {
+        // A process that is its own parent, and two that point at each other
+        let processes = [process(5, Some(5), 0.0, 0), process(7, Some(8), 1.0, 10), process(8, Some(7), 2.0, 20)];
+        let refs: Vec<&ProcessInfo> = processes.iter().collect();
+        let rows = build(&refs, &HashSet::new());
+        assert_eq!(layout(&rows), vec![(5, 0), (7, 0), (8, 1)]);
+        assert_eq!(rows[1].subtree_memory, 30);
+    }
+
+    #[test]
+    fn collapsed_subtrees_are_hidden_but_counted() {
+        let processes = sample();
+        let refs: Vec<&ProcessInfo> = processes.iter().collect();
+        let rows = build(&refs, &HashSet::from([10]));
+        assert_eq!(layout(&rows), vec![(1, 0), (10, 1), (11, 1)]);
+        assert!(rows[1].collapsed);
+        assert_eq!(rows[1].subtree_memory, 200 + 800 + 1600);
+    }
+
+    #[test]
+    fn sums_subtree_totals() {
+        let processes = sample();
+        let refs: Vec<&ProcessInfo> = processes.iter().collect();
+        let rows = build(&refs, &HashSet::new());
+        let totals: Vec<(u32, f32, u64)> = rows.iter().map(|r| (r.proc.pid, r.subtree_cpu, r.subtree_memory)).collect();
+        assert_eq!(
+            totals,
+            vec![(1, 31.0, 3100), (10, 26.0, 2600), (20, 24.0, 2400), (30, 16.0, 1600), (11, 4.0, 400)]
+        );
+    }
+
+    #[test]
+    fn descendants_come_before_their_parents() {
+        let processes = sample();
+        let pids: Vec<u32> = descendants(&processes, 1).iter().map(|key| key.pid).collect();
+        assert_eq!(pids.len(), 4);
+        let position = |pid| pids.iter().position(|p| *p == pid).unwrap();
+        assert!(position(30) < position(20));
+        assert!(position(20) < position(10));
+
+        let pids: Vec<u32> = descendants(&processes, 10).iter().map(|key| key.pid).collect();
+        assert_eq!(pids, vec![30, 20]);
+        assert!(descendants(&processes, 30).is_empty());
+    }
+
+    #[test]
+    fn descendants_stop_at_a_loop_back_to_the_root() {
+        let processes = vec![process(7, Some(8), 0.0, 0), process(8, Some(7), 0.0, 0)];
+        let pids: Vec<u32> = descendants(&processes, 7).iter().map(|key| key.pid).collect();
+        assert_eq!(pids, vec![8]);
+    }
+}
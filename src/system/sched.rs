@@ -0,0 +1,211 @@
+use super::procstat;
+use std::io;
+use std::path::Path;
+
+/// Linux I/O scheduling classes, as used by ionice(1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IoClass {
+    /// No class set; the kernel derives one from the nice value
+    None,
+    Realtime,
+    BestEffort,
+    Idle,
+}
+
+impl IoClass {
+    pub fn all() -> [IoClass; 4] {
+        [IoClass::None, IoClass::Realtime, IoClass::BestEffort, IoClass::Idle]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IoClass::None => "none",
+            IoClass::Realtime => "realtime",
+            IoClass::BestEffort => "best-effort",
+            IoClass::Idle => "idle",
+        }
+    }
+
+    /// Whether the class takes a 0-7 priority level.
+    pub fn has_level(&self) -> bool {
+        matches!(self, IoClass::Realtime | IoClass::BestEffort)
+    }
+
+    fn from_raw(class: i32) -> IoClass {
+        match class {
+            1 => IoClass::Realtime,
+            2 => IoClass::BestEffort,
+            3 => IoClass::Idle,
+            _ => IoClass::None,
+        }
+    }
+
+    fn raw(&self) -> i32 {
+        match self {
+            IoClass::None => 0,
+            IoClass::Realtime => 1,
+            IoClass::BestEffort => 2,
+            IoClass::Idle => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IoPriority {
+    pub class: IoClass,
+    /// 0 (highest) to 7 (lowest)
+    pub level: u8,
+}
+
+impl IoPriority {
+    /// The priority as ionice(1) would describe it, e.g. `best-effort/4`.
+    pub fn describe(&self) -> String {
+        if self.class.has_level() {
+            format!("{}/{}", self.class.name(), self.level)
+        } else {
+            self.class.name().to_string()
+        }
+    }
+}
+
+const IOPRIO_WHO_PROCESS: libc::c_long = 1;
+const IOPRIO_CLASS_SHIFT: i32 = 13;
+
+/// Current nice value of `pid`.
+pub fn nice(pid: u32) -> Result<i64, String> {
+    procstat::read(&Path::new("/proc").join(pid.to_string()).join("stat"))
+        .map(|stat| stat.nice)
+        .ok_or_else(|| "no such process (it has already exited)".to_string())
+}
+
+/// Sets the nice value of every thread of `pid`, like `renice` does for a
+/// whole process.
+pub fn set_nice(pid: u32, nice: i32) -> Result<(), String> {
+    for tid in tasks(pid)? {
+        // SAFETY: setpriority has no memory-safety preconditions
+        if unsafe { libc::setpriority(libc::PRIO_PROCESS as _, tid as libc::id_t, nice) } != 0 {
+            return Err(describe(io::Error::last_os_error(), "raising priority needs root"));
+        }
+    }
+    Ok(())
+}
+
+/// Current I/O scheduling class and level of `pid`.
+pub fn io_priority(pid: u32) -> Result<IoPriority, String> {
+    // SAFETY: ioprio_get takes two integers and returns one
+    let raw = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid as libc::c_long) };
+    if raw < 0 {
+        return Err(describe(io::Error::last_os_error(), "needs root"));
+    }
+    let raw = raw as i32;
+    Ok(IoPriority {
+        class: IoClass::from_raw(raw >> IOPRIO_CLASS_SHIFT),
+        level: (raw & ((1 << IOPRIO_CLASS_SHIFT) - 1)) as u8,
+    })
+}
+
+/// Sets the I/O scheduling class and level of every thread of `pid`.
+pub fn set_io_priority(pid: u32, priority: IoPriority) -> Result<(), String> {
+    let level = if priority.class.has_level() { priority.level.min(7) as i32 } else { 0 };
+    let raw = (priority.class.raw() << IOPRIO_CLASS_SHIFT) | level;
+
+    for tid in tasks(pid)? {
+        // SAFETY: ioprio_set takes three integers
+        let result = unsafe {
+            libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, tid as libc::c_long, raw as libc::c_long)
+        };
+        if result != 0 {
+            return Err(describe(io::Error::last_os_error(), "the realtime class needs root"));
+        }
+    }
+    Ok(())
+}
+
+/// CPUs `pid` may run on, as one flag per CPU.
+pub fn affinity(pid: u32) -> Result<Vec<bool>, String> {
+    // SAFETY: cpu_set_t is plain data, and the size passed matches it
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            return Err(describe(io::Error::last_os_error(), "needs root"));
+        }
+        Ok((0..cpu_count()).map(|cpu| libc::CPU_ISSET(cpu, &set)).collect())
+    }
+}
+
+/// Restricts every thread of `pid` to the CPUs flagged in `cpus`, like
+/// `taskset -a`.
+pub fn set_affinity(pid: u32, cpus: &[bool]) -> Result<(), String> {
+    if !cpus.contains(&true) {
+        return Err("at least one CPU must be selected".to_string());
+    }
+
+    // SAFETY: cpu_set_t is plain data, and the size passed matches it
+    let set = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for cpu in cpus.iter().enumerate().filter(|(_, on)| **on).map(|(cpu, _)| cpu) {
+            libc::CPU_SET(cpu, &mut set);
+        }
+        set
+    };
+
+    for tid in tasks(pid)? {
+        // SAFETY: as above
+        let result =
+            unsafe { libc::sched_setaffinity(tid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &set) };
+        if result != 0 {
+            return Err(describe(io::Error::last_os_error(), "needs root"));
+        }
+    }
+    Ok(())
+}
+
+/// Number of configured CPUs, online or not.
+pub fn cpu_count() -> usize {
+    // SAFETY: sysconf has no preconditions
+    let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) };
+    (count.max(1) as usize).min(libc::CPU_SETSIZE as usize)
+}
+
+/// CPU set as a taskset-style list, e.g. `0-3,6`.
+pub fn format_cpu_list(cpus: &[bool]) -> String {
+    let mut ranges: Vec<String> = Vec::new();
+    let mut start = None;
+
+    for i in 0..=cpus.len() {
+        match (cpus.get(i).copied().unwrap_or(false), start) {
+            (true, None) => start = Some(i),
+            (false, Some(first)) => {
+                ranges.push(if i - 1 == first { first.to_string() } else { format!("{}-{}", first, i - 1) });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if ranges.is_empty() {
+        "none".to_string()
+    } else {
+        ranges.join(",")
+    }
+}
+
+/// Thread IDs of `pid`. Nice values, I/O priorities and affinity are all
+/// per thread on Linux, so changing only the PID would miss the others.
+fn tasks(pid: u32) -> Result<Vec<u32>, String> {
+    let entries = std::fs::read_dir(Path::new("/proc").join(pid.to_string()).join("task"))
+        .map_err(|_| "no such process (it has already exited)".to_string())?;
+    Ok(entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str().and_then(|s| s.parse().ok()))
+        .collect())
+}
+
+fn describe(err: io::Error, permission_hint: &str) -> String {
+    match err.raw_os_error() {
+        Some(libc::EPERM) | Some(libc::EACCES) => format!("permission denied ({})", permission_hint),
+        Some(libc::ESRCH) => "no such process (it has already exited)".to_string(),
+        Some(libc::EINVAL) => "invalid value for this process or system".to_string(),
+        _ => err.to_string(),
+    }
+}
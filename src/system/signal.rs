@@ -0,0 +1,84 @@
+use std::io;
+
+/// A signal offered in the Processes signal menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signal {
+    pub name: &'static str,
+    pub number: i32,
+    pub description: &'static str,
+}
+
+pub const TERM: Signal = Signal {
+    name: "TERM",
+    number: libc::SIGTERM,
+    description: "ask the process to exit cleanly",
+};
+
+pub const KILL: Signal = Signal {
+    name: "KILL",
+    number: libc::SIGKILL,
+    description: "terminate immediately, cannot be caught",
+};
+
+/// Menu order: the polite default first, the forceful one last.
+pub const MENU: [Signal; 9] = [
+    TERM,
+    Signal {
+        name: "HUP",
+        number: libc::SIGHUP,
+        description: "hang up; many daemons reload their config",
+    },
+    Signal {
+        name: "INT",
+        number: libc::SIGINT,
+        description: "interrupt, like Ctrl-C",
+    },
+    Signal {
+        name: "QUIT",
+        number: libc::SIGQUIT,
+        description: "quit and dump core",
+    },
+    Signal {
+        name: "USR1",
+        number: libc::SIGUSR1,
+        description: "user-defined signal 1",
+    },
+    Signal {
+        name: "USR2",
+        number: libc::SIGUSR2,
+        description: "user-defined signal 2",
+    },
+    Signal {
+        name: "STOP",
+        number: libc::SIGSTOP,
+        description: "pause the process, cannot be caught",
+    },
+    Signal {
+        name: "CONT",
+        number: libc::SIGCONT,
+        description: "resume a stopped process",
+    },
+    KILL,
+];
+
+/// Sends `signal` to `pid` with kill(2), describing the common failures in
+/// words a user can act on.
+pub fn send(pid: u32, signal: Signal) -> Result<(), String> {
+    // PID 0 and negative values would signal whole process groups
+    let pid = libc::pid_t::try_from(pid)
+        .ok()
+        .filter(|p| *p > 0)
+        .ok_or_else(|| format!("invalid PID {}", pid))?;
+
+    // SAFETY: kill has no memory-safety preconditions
+    if unsafe { libc::kill(pid, signal.number) } == 0 {
+        return Ok(());
+    }
+
+    let err = io::Error::last_os_error();
+    Err(match err.raw_os_error() {
+        Some(libc::EPERM) => "permission denied (owned by another user; try running as root)".to_string(),
+        Some(libc::ESRCH) => "no such process (it has already exited)".to_string(),
+        _ => err.to_string(),
+    })
+}
@@ -0,0 +1,138 @@
+use std::process::Command;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SmartInfo {
+    pub passed: Option<bool>,
+    pub temperature: Option<i64>,
+    pub power_on_hours: Option<u64>,
+    pub reallocated_sectors: Option<u64>,
+    pub pending_sectors: Option<u64>,
+    pub percentage_used: Option<u64>,
+    pub media_errors: Option<u64>,
+}
+
+impl SmartInfo {
+    /// Human readable problems worth surfacing outside the device popup.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if self.passed == Some(false) {
+            warnings.push("overall health FAILED".to_string());
+        }
+        if let Some(n) = self.reallocated_sectors.filter(|&n| n > 0) {
+            warnings.push(format!("{} reallocated sectors", n));
+        }
+        if let Some(n) = self.pending_sectors.filter(|&n| n > 0) {
+            warnings.push(format!("{} pending sectors", n));
+        }
+        if let Some(n) = self.media_errors.filter(|&n| n > 0) {
+            warnings.push(format!("{} media errors", n));
+        }
+        if let Some(n) = self.percentage_used.filter(|&n| n >= 90) {
+            warnings.push(format!("{}% of rated endurance used", n));
+        }
+        if let Some(t) = self.temperature.filter(|&t| t >= 60) {
+            warnings.push(format!("temperature {}°C", t));
+        }
+
+        warnings
+    }
+}
+
+/// Runs `smartctl` against a block device such as `sda` or `nvme0n1`.
+pub fn query(device: &str) -> Option<SmartInfo> {
+    let output = Command::new("smartctl")
+        .args(["--json", "-a", &format!("/dev/{}", device)])
+        .output()
+        .ok()?;
+
+    // smartctl's exit status is a bitmask that is non-zero for failing
+    // drives, so parse whatever JSON it printed instead of checking it
+    parse(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses `smartctl --json -a` output for both ATA and NVMe drives.
+/// Returns `None` if the output carries no health data (e.g. the device
+/// could not be opened).
+pub fn parse(json_str: &str) -> Option<SmartInfo> {
+    let json = serde_json::from_str::<serde_json::Value>(json_str).ok()?;
+
+    let nvme = &json["nvme_smart_health_information_log"];
+    let attributes = json["ata_smart_attributes"]["table"].as_array();
+
+    if json["smart_status"].is_null() && nvme.is_null() && attributes.is_none() {
+        return None;
+    }
+
+    let ata_raw = |id: u64| {
+        attributes?
+            .iter()
+            .find(|attr| attr["id"].as_u64() == Some(id))
+            .and_then(|attr| attr["raw"]["value"].as_u64())
+    };
+
+    Some(SmartInfo {
+        passed: json["smart_status"]["passed"].as_bool(),
+        temperature: json["temperature"]["current"]
+            .as_i64()
+            .or_else(|| nvme["temperature"].as_i64()),
+        power_on_hours: json["power_on_time"]["hours"]
+            .as_u64()
+            .or_else(|| nvme["power_on_hours"].as_u64()),
+        reallocated_sectors: ata_raw(5),
+        pending_sectors: ata_raw(197),
+        percentage_used: nvme["percentage_used"].as_u64(),
+        media_errors: nvme["media_errors"].as_u64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sata_drive() {
+        let info = parse(include_str!("fixtures/smartctl_sata.json")).unwrap();
+        assert_eq!(info.passed, Some(true));
+        assert_eq!(info.temperature, Some(34));
+        assert_eq!(info.power_on_hours, Some(31542));
+        assert_eq!(info.reallocated_sectors, Some(8));
+        assert_eq!(info.pending_sectors, Some(0));
+        assert_eq!(info.percentage_used, None);
+        assert_eq!(info.media_errors, None);
+        assert_eq!(info.warnings(), vec!["8 reallocated sectors".to_string()]);
+    }
+
+    #[test]
+    fn parses_nvme_drive() {
+        let info = parse(include_str!("fixtures/smartctl_nvme.json")).unwrap();
+        assert_eq!(info.passed, Some(true));
+        assert_eq!(info.temperature, Some(41));
+        assert_eq!(info.power_on_hours, Some(2871));
+        assert_eq!(info.reallocated_sectors, None);
+        assert_eq!(info.percentage_used, Some(3));
+        assert_eq!(info.media_errors, Some(0));
+        assert!(info.warnings().is_empty());
+    }
+
+    #[test]
+    fn reports_failing_drive() {
+        let info = parse(include_str!("fixtures/smartctl_failing.json")).unwrap();
+        assert_eq!(info.passed, Some(false));
+        assert_eq!(
+            info.warnings(),
+            vec![
+                "overall health FAILED".to_string(),
+                "14 media errors".to_string(),
+                "97% of rated endurance used".to_string(),
+                "temperature 72°C".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_output_without_health_data() {
+        assert_eq!(parse(include_str!("fixtures/smartctl_permission_denied.json")), None);
+        assert_eq!(parse("not json"), None);
+    }
+}
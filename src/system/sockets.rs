@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[derive(Debug, Clone)]
+pub struct Connection {
+    pub protocol: &'static str,
+    pub local: String,
+    pub remote: String,
+    pub state: &'static str,
+    pub send_queue: u64,
+    pub recv_queue: u64,
+    pub inode: u64,
+    pub pid: Option<u32>,
+    pub process: Option<String>,
+}
+
+impl Connection {
+    pub fn is_listening(&self) -> bool {
+        self.state == "LISTEN" || self.state == "UNCONN"
+    }
+}
+
+/// Reads all TCP and UDP sockets and resolves their owning processes
+/// from the inode map returned by `socket_owners`.
+pub fn read_connections(owners: &HashMap<u64, u32>) -> Vec<Connection> {
+    let mut names: HashMap<u32, Option<String>> = HashMap::new();
+
+    let mut connections: Vec<Connection> = [
+        ("tcp", "/proc/net/tcp"),
+        ("tcp6", "/proc/net/tcp6"),
+        ("udp", "/proc/net/udp"),
+        ("udp6", "/proc/net/udp6"),
+    ]
+    .iter()
+    .filter_map(|(protocol, path)| {
+        std::fs::read_to_string(path)
+            .ok()
+            .map(|content| parse_proc_net(protocol, &content))
+    })
+    .flatten()
+    .map(|mut conn| {
+        conn.pid = owners.get(&conn.inode).copied();
+        conn.process = conn.pid.and_then(|pid| {
+            names
+                .entry(pid)
+                .or_insert_with(|| {
+                    std::fs::read_to_string(format!("/proc/{}/comm", pid))
+                        .ok()
+                        .map(|s| s.trim().to_string())
+                })
+                .clone()
+        });
+        conn
+    })
+    .collect();
+
+    connections.sort_by(|a, b| {
+        (!a.is_listening(), a.protocol, &a.local).cmp(&(!b.is_listening(), b.protocol, &b.local))
+    });
+    connections
+}
+
+/// Maps socket inodes to the PID holding them open, by scanning
+/// `/proc/<pid>/fd`. Sockets of other users' processes are only visible
+/// with sufficient privileges.
+pub fn socket_owners() -> HashMap<u64, u32> {
+    let mut owners = HashMap::new();
+
+    let Ok(procs) = std::fs::read_dir("/proc") else {
+        return owners;
+    };
+
+    for proc_entry in procs.flatten() {
+        let Some(pid) = proc_entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(proc_entry.path().join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let Ok(target) = std::fs::read_link(fd.path()) else {
+                continue;
+            };
+            let target = target.to_string_lossy();
+            if let Some(inode) = target
+                .strip_prefix("socket:[")
+                .and_then(|s| s.strip_suffix(']'))
+                .and_then(|s| s.parse::<u64>().ok())
+            {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+
+    owners
+}
+
+fn parse_proc_net(protocol: &'static str, content: &str) -> Vec<Connection> {
+    let is_tcp = protocol.starts_with("tcp");
+
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+
+            let (send_queue, recv_queue) = fields[4].split_once(':')?;
+            let state = u8::from_str_radix(fields[3], 16).ok()?;
+
+            Some(Connection {
+                protocol,
+                local: parse_address(fields[1])?,
+                remote: parse_address(fields[2])?,
+                state: if is_tcp { tcp_state(state) } else { udp_state(state) },
+                send_queue: u64::from_str_radix(send_queue, 16).unwrap_or(0),
+                recv_queue: u64::from_str_radix(recv_queue, 16).unwrap_or(0),
+                inode: fields[9].parse().unwrap_or(0),
+                pid: None,
+                process: None,
+            })
+        })
+        .collect()
+}
+
+/// Decodes `0100007F:0050` or the 32-digit IPv6 form. The kernel prints
+/// each 32-bit word of the address as a host-order integer.
+fn parse_address(field: &str) -> Option<String> {
+    let (addr, port) = field.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let port = if port == 0 { "*".to_string() } else { port.to_string() };
+
+    let words: Vec<u32> = (0..addr.len() / 8)
+        .map(|i| u32::from_str_radix(&addr[i * 8..i * 8 + 8], 16))
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    match words.as_slice() {
+        [w] => {
+            let ip = Ipv4Addr::from(w.to_ne_bytes());
+            let ip = if ip.is_unspecified() { "*".to_string() } else { ip.to_string() };
+            Some(format!("{}:{}", ip, port))
+        }
+        [a, b, c, d] => {
+            let mut bytes = [0u8; 16];
+            for (chunk, word) in bytes.chunks_mut(4).zip([a, b, c, d]) {
+                chunk.copy_from_slice(&word.to_ne_bytes());
+            }
+            let ip = Ipv6Addr::from(bytes);
+            let ip = match ip.to_ipv4_mapped() {
+                Some(v4) => v4.to_string(),
+                None if ip.is_unspecified() => "*".to_string(),
+                None => ip.to_string(),
+            };
+            Some(format!("[{}]:{}", ip, port))
+        }
+        _ => None,
+    }
+}
+
+fn tcp_state(state: u8) -> &'static str {
+    match state {
+        0x01 => "ESTAB",
+        0x02 => "SYN-SENT",
+        0x03 => "SYN-RECV",
+        0x04 => "FIN-WAIT-1",
+        0x05 => "FIN-WAIT-2",
+        0x06 => "TIME-WAIT",
+        0x07 => "CLOSE",
+        0x08 => "CLOSE-WAIT",
+        0x09 => "LAST-ACK",
+        0x0A => "LISTEN",
+        0x0B => "CLOSING",
+        _ => "UNKNOWN",
+    }
+}
+
+fn udp_state(state: u8) -> &'static str {
+    match state {
+        0x01 => "ESTAB",
+        _ => "UNCONN",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints(connections: &[Connection]) -> Vec<(&str, &str, &str)> {
+        connections
+            .iter()
+            .map(|c| (c.local.as_str(), c.remote.as_str(), c.state))
+            .collect()
+    }
+
+    // Addresses are printed in host order; the fixtures come from a
+    // little-endian machine
+    #[test]
+    fn parses_ipv4_sockets() {
+        let connections = parse_proc_net("tcp", include_str!("fixtures/proc_net_tcp.txt"));
+        assert_eq!(
+            endpoints(&connections),
+            vec![
+                ("127.0.0.1:631", "*:*", "LISTEN"),
+                ("*:22", "*:*", "LISTEN"),
+                ("10.0.2.15:54946", "140.186.216.34:443", "ESTAB"),
+                ("10.0.2.15:22", "10.0.2.1:58312", "ESTAB"),
+            ]
+        );
+        assert_eq!(connections[0].inode, 24571);
+        assert_eq!((connections[3].send_queue, connections[3].recv_queue), (36, 0));
+        assert!(connections[0].is_listening());
+        assert!(!connections[2].is_listening());
+    }
+
+    #[test]
+    fn parses_ipv6_and_mapped_sockets() {
+        let connections = parse_proc_net("tcp6", include_str!("fixtures/proc_net_tcp6.txt"));
+        assert_eq!(
+            endpoints(&connections),
+            vec![
+                ("[::1]:631", "[*]:*", "LISTEN"),
+                ("[*]:8080", "[*]:*", "LISTEN"),
+                ("[10.0.2.15]:8080", "[10.0.2.1]:50000", "ESTAB"),
+                ("[2001:db8::a000:1]:22", "[2001:db8::fec0:2]:54321", "TIME-WAIT"),
+            ]
+        );
+    }
+
+    #[test]
+    fn udp_sockets_are_unconnected_unless_established() {
+        let content = "  sl  local_address rem_address   st\n   0: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 20455 2\n";
+        let connections = parse_proc_net("udp", content);
+        assert_eq!(endpoints(&connections), vec![("127.0.0.53:53", "*:*", "UNCONN")]);
+    }
+
+    #[test]
+    fn rejects_malformed_addresses() {
+        assert_eq!(parse_address("0100007F"), None);
+        assert_eq!(parse_address("0100007F:zz"), None);
+        assert_eq!(parse_address("0100:0050"), None);
+        assert_eq!(parse_address("XX00007F:0050"), None);
+    }
+}
@@ -0,0 +1,325 @@
+use super::read_trimmed;
+use std::path::Path;
+
+const SYS_BLOCK: &str = "/sys/class/block";
+
+#[derive(Debug, Clone)]
+pub struct MdMember {
+    pub name: String,
+    pub failed: bool,
+    pub spare: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyncProgress {
+    pub action: String,
+    pub percent: Option<f32>,
+    pub finish: Option<String>,
+    pub speed: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MdArray {
+    pub name: String,
+    pub level: String,
+    pub state: String,
+    pub members: Vec<MdMember>,
+    pub total_disks: usize,
+    pub active_disks: usize,
+    pub status: String,
+    pub sync: Option<SyncProgress>,
+}
+
+impl MdArray {
+    pub fn is_degraded(&self) -> bool {
+        self.active_disks < self.total_disks || self.members.iter().any(|m| m.failed)
+    }
+}
+
+/// A block device and the devices it is built on, e.g. an LVM volume on a
+/// LUKS mapping on an md array on two partitions.
+#[derive(Debug, Clone)]
+pub struct StackNode {
+    pub name: String,
+    pub kind: String,
+    pub size: u64,
+    pub children: Vec<StackNode>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StorageStack {
+    pub arrays: Vec<MdArray>,
+    pub stacks: Vec<StackNode>,
+}
+
+pub fn read() -> StorageStack {
+    let arrays = std::fs::read_to_string("/proc/mdstat")
+        .map(|content| parse_mdstat(&content))
+        .unwrap_or_default();
+
+    // Start from the top of each stack: md/dm devices nothing else is built on
+    let mut stacks: Vec<StackNode> = std::fs::read_dir(SYS_BLOCK)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .filter(|name| name.starts_with("dm-") || name.starts_with("md"))
+                .filter(|name| list_dir(&Path::new(SYS_BLOCK).join(name).join("holders")).is_empty())
+                .map(|name| build_node(&name, 0))
+                .collect()
+        })
+        .unwrap_or_default();
+    stacks.sort_by(|a, b| a.name.cmp(&b.name));
+
+    StorageStack { arrays, stacks }
+}
+
+fn build_node(name: &str, depth: usize) -> StackNode {
+    let dev = Path::new(SYS_BLOCK).join(name);
+
+    let size = read_trimmed(&dev.join("size"))
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(|sectors| sectors * 512)
+        .unwrap_or(0);
+
+    let (display_name, kind) = if name.starts_with("dm-") {
+        let dm_name = read_trimmed(&dev.join("dm/name")).unwrap_or_else(|| name.to_string());
+        let uuid = read_trimmed(&dev.join("dm/uuid")).unwrap_or_default();
+        (format!("{} ({})", dm_name, name), dm_kind(&uuid).to_string())
+    } else if name.starts_with("md") {
+        let level = read_trimmed(&dev.join("md/level")).unwrap_or_else(|| "md".to_string());
+        (name.to_string(), level)
+    } else if dev.join("partition").exists() {
+        (name.to_string(), "part".to_string())
+    } else {
+        (name.to_string(), "disk".to_string())
+    };
+
+    // Guard against unexpected cycles in sysfs
+    let mut children: Vec<StackNode> = if depth < 8 {
+        list_dir(&dev.join("slaves"))
+            .iter()
+            .map(|slave| build_node(slave, depth + 1))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Partitions have no slaves; show the disk they live on instead
+    if children.is_empty() && kind == "part" {
+        if let Some(parent) = std::fs::canonicalize(&dev)
+            .ok()
+            .and_then(|p| p.parent().and_then(|p| p.file_name()).map(|n| n.to_string_lossy().to_string()))
+        {
+            children.push(build_node(&parent, depth + 1));
+        }
+    }
+    children.sort_by(|a, b| a.name.cmp(&b.name));
+
+    StackNode {
+        name: display_name,
+        kind,
+        size,
+        children,
+    }
+}
+
+fn dm_kind(uuid: &str) -> &'static str {
+    if uuid.starts_with("LVM-") {
+        "lvm"
+    } else if uuid.starts_with("CRYPT-") {
+        "crypt"
+    } else if uuid.starts_with("mpath-") {
+        "multipath"
+    } else if uuid.starts_with("part") {
+        "dm-part"
+    } else {
+        "dm"
+    }
+}
+
+/// Parses `/proc/mdstat` into one entry per array.
+pub fn parse_mdstat(content: &str) -> Vec<MdArray> {
+    let mut arrays: Vec<MdArray> = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with("md") {
+            let Some((name, rest)) = line.split_once(" : ") else {
+                continue;
+            };
+            let mut tokens = rest.split_whitespace().peekable();
+            let state = tokens.next().unwrap_or("unknown").to_string();
+            while tokens.peek().is_some_and(|t| t.starts_with('(')) {
+                tokens.next();
+            }
+            let level = match tokens.peek() {
+                Some(t) if !t.contains('[') => tokens.next().unwrap_or_default().to_string(),
+                _ => "-".to_string(),
+            };
+            let members = tokens
+                .filter_map(|t| {
+                    let (dev, flags) = t.split_once('[')?;
+                    Some(MdMember {
+                        name: dev.to_string(),
+                        failed: flags.contains("(F)"),
+                        spare: flags.contains("(S)"),
+                    })
+                })
+                .collect();
+
+            arrays.push(MdArray {
+                name: name.trim().to_string(),
+                level,
+                state,
+                members,
+                total_disks: 0,
+                active_disks: 0,
+                status: String::new(),
+                sync: None,
+            });
+            continue;
+        }
+
+        let Some(array) = arrays.last_mut() else {
+            continue;
+        };
+        if !line.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        if let Some(progress) = parse_sync_line(line) {
+            array.sync = Some(progress);
+            continue;
+        }
+
+        // e.g. "1953382464 blocks super 1.2 [2/1] [U_]"
+        for token in line.split_whitespace() {
+            let Some(inner) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) else {
+                continue;
+            };
+            if let Some((total, active)) = inner.split_once('/') {
+                if let (Ok(total), Ok(active)) = (total.parse(), active.parse()) {
+                    array.total_disks = total;
+                    array.active_disks = active;
+                }
+            } else if !inner.is_empty() && inner.chars().all(|c| c == 'U' || c == '_') {
+                array.status = token.to_string();
+            }
+        }
+    }
+
+    arrays
+}
+
+fn parse_sync_line(line: &str) -> Option<SyncProgress> {
+    const ACTIONS: [&str; 5] = ["resync", "recovery", "reshape", "check", "repair"];
+
+    let trimmed = line.trim_start();
+    // Skip the "[==>....]" bar if present
+    let trimmed = match trimmed.strip_prefix('[') {
+        Some(rest) => rest.split_once(']').map(|(_, r)| r.trim_start()).unwrap_or(rest),
+        None => trimmed,
+    };
+
+    let action = ACTIONS.iter().find(|a| trimmed.starts_with(*a))?;
+    let after = trimmed[action.len()..].trim_start().strip_prefix('=')?.trim_start();
+
+    let mut progress = SyncProgress {
+        action: action.to_string(),
+        percent: None,
+        finish: None,
+        speed: None,
+    };
+
+    let mut tokens = after.split_whitespace();
+    match tokens.next() {
+        // resync=DELAYED / resync=PENDING
+        Some(t) if !t.ends_with('%') => progress.action = format!("{} {}", action, t.to_lowercase()),
+        Some(t) => progress.percent = t.trim_end_matches('%').parse().ok(),
+        None => {}
+    }
+    for token in tokens {
+        if let Some(v) = token.strip_prefix("finish=") {
+            progress.finish = Some(v.to_string());
+        } else if let Some(v) = token.strip_prefix("speed=") {
+            progress.speed = Some(v.to_string());
+        }
+    }
+
+    Some(progress)
+}
+
+fn list_dir(path: &Path) -> Vec<String> {
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_degraded_raid1() {
+        let arrays = parse_mdstat(include_str!("fixtures/mdstat_degraded.txt"));
+        assert_eq!(arrays.len(), 1);
+
+        let md0 = &arrays[0];
+        assert_eq!(md0.name, "md0");
+        assert_eq!(md0.level, "raid1");
+        assert_eq!(md0.state, "active");
+        assert_eq!(
+            md0.members.iter().map(|m| (m.name.as_str(), m.failed)).collect::<Vec<_>>(),
+            vec![("sdb1", true), ("sda1", false)]
+        );
+        assert_eq!((md0.total_disks, md0.active_disks), (2, 1));
+        assert_eq!(md0.status, "[U_]");
+        assert!(md0.sync.is_none());
+        assert!(md0.is_degraded());
+    }
+
+    #[test]
+    fn parses_resyncing_arrays() {
+        let arrays = parse_mdstat(include_str!("fixtures/mdstat_resync.txt"));
+        assert_eq!(
+            arrays.iter().map(|a| a.name.as_str()).collect::<Vec<_>>(),
+            vec!["md1", "md2", "md127"]
+        );
+
+        let md1 = &arrays[0];
+        assert_eq!(md1.level, "raid1");
+        assert_eq!(md1.status, "[UU]");
+        assert!(!md1.is_degraded());
+        let sync = md1.sync.as_ref().unwrap();
+        assert_eq!(sync.action, "resync");
+        assert_eq!(sync.percent, Some(37.4));
+        assert_eq!(sync.finish.as_deref(), Some("24.6min"));
+        assert_eq!(sync.speed.as_deref(), Some("206912K/sec"));
+
+        // The "(auto-read-only)" flag is skipped to find the level
+        let md2 = &arrays[1];
+        assert_eq!(md2.level, "raid5");
+        assert_eq!((md2.total_disks, md2.active_disks), (3, 3));
+        assert_eq!(md2.members.iter().filter(|m| m.spare).count(), 1);
+        let sync = md2.sync.as_ref().unwrap();
+        assert_eq!(sync.action, "resync pending");
+        assert_eq!(sync.percent, None);
+
+        let md127 = &arrays[2];
+        assert_eq!(md127.state, "inactive");
+        assert_eq!(md127.level, "-");
+        assert!(md127.members[0].spare);
+        assert_eq!(md127.total_disks, 0);
+    }
+
+    #[test]
+    fn handles_no_arrays() {
+        assert!(parse_mdstat(include_str!("fixtures/mdstat_empty.txt")).is_empty());
+        assert!(parse_mdstat("").is_empty());
+    }
+}
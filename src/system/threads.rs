@@ -0,0 +1,86 @@
+use super::procstat;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    pub state: char,
+    /// Percent of one CPU since the previous sample
+    pub cpu_usage: f32,
+    /// CPU the thread last ran on
+    pub processor: u32,
+}
+
+/// The threads of one process from `/proc/<pid>/task`. Per-thread CPU usage
+/// is the CPU time used between two calls to [`ProcessThreads::refresh`].
+#[derive(Debug, Clone, Default)]
+pub struct ProcessThreads {
+    pub threads: Vec<ThreadInfo>,
+    /// CPU ticks per thread at the previous refresh
+    samples: HashMap<u32, u64>,
+    sampled_at: Option<Instant>,
+}
+
+impl ProcessThreads {
+    pub fn refresh(&mut self, pid: u32) {
+        let now = Instant::now();
+        let elapsed = self.sampled_at.map(|t| now.duration_since(t).as_secs_f64()).unwrap_or(0.0);
+        let ticks_per_sec = procstat::clock_ticks() as f64;
+        let task_dir = Path::new("/proc").join(pid.to_string()).join("task");
+
+        let mut samples = HashMap::new();
+        let mut threads: Vec<ThreadInfo> = std::fs::read_dir(&task_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let tid: u32 = entry.file_name().to_str()?.parse().ok()?;
+                let stat = procstat::read(&entry.path().join("stat"))?;
+                let ticks = stat.utime + stat.stime;
+
+                // A thread seen for the first time has no usage yet
+                let cpu_usage = match self.samples.get(&tid) {
+                    Some(prev) if elapsed > 0.0 => {
+                        (ticks.saturating_sub(*prev) as f64 / ticks_per_sec / elapsed * 100.0) as f32
+                    }
+                    _ => 0.0,
+                };
+                samples.insert(tid, ticks);
+
+                Some(ThreadInfo {
+                    tid,
+                    name: std::fs::read_to_string(entry.path().join("comm"))
+                        .map(|s| s.trim().to_string())
+                        .unwrap_or_default(),
+                    state: stat.state,
+                    cpu_usage,
+                    processor: stat.processor,
+                })
+            })
+            .collect();
+        threads.sort_by_key(|t| t.tid);
+
+        self.threads = threads;
+        self.samples = samples;
+        self.sampled_at = Some(now);
+    }
+}
+
+/// Long name for a one-letter task state from `/proc/<pid>/stat`.
+pub fn state_name(state: char) -> &'static str {
+    match state {
+        'R' => "running",
+        'S' => "sleeping",
+        'D' => "disk sleep",
+        'T' => "stopped",
+        't' => "tracing stop",
+        'Z' => "zombie",
+        'X' => "dead",
+        'I' => "idle",
+        'P' => "parked",
+        _ => "unknown",
+    }
+}
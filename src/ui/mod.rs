@@ -2,7 +2,11 @@ mod tree;
 mod tabs;
 pub mod widgets;
 
-use crate::app::{App, Mode};
+use crate::app::{App, Mode, ProcessTuning, Tuning};
+use crate::system::sched::{self, IoClass};
+use crate::system::{format_bytes, format_uptime, signal, ProcessColumn, ProcessKey};
+use crate::system::netdev::WirelessInfo;
+use crate::system::smart::SmartInfo;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
@@ -43,9 +47,34 @@ pub fn draw(frame: &mut Frame, app: &App) {
         draw_device_popup(frame, app);
     }
 
-    // Draw kill confirmation popup if active
-    if app.show_kill_confirm {
-        draw_kill_confirm(frame, app);
+    // Draw network interface popup if active
+    if app.show_network_popup {
+        draw_network_popup(frame, app);
+    }
+
+    // Draw signal menu if active
+    if app.show_signal_menu {
+        draw_signal_menu(frame, app);
+    }
+
+    // Draw export confirmation popup if active
+    if app.show_export_confirm {
+        draw_export_confirm(frame, app);
+    }
+
+    // Draw delete confirmation popup if active
+    if app.show_delete_confirm {
+        draw_delete_confirm(frame, app);
+    }
+
+    // Draw renice / ionice / affinity popup if active
+    if let Some(tuning) = &app.process_tuning {
+        draw_tuning_popup(frame, tuning);
+    }
+
+    // Draw process column chooser if active
+    if app.show_column_chooser {
+        draw_column_chooser(frame, app);
     }
 }
 
@@ -61,6 +90,10 @@ fn draw_command_line(frame: &mut Frame, app: &App, area: Rect) {
             };
             (text, style)
         }
+        Mode::Filter => (
+            format!("/{}_", app.process_filter),
+            Style::default().fg(Color::White),
+        ),
         Mode::Normal => {
             if let Some(err) = &app.command_error {
                 (err.clone(), Style::default().fg(Color::Red))
@@ -74,24 +107,139 @@ fn draw_command_line(frame: &mut Frame, app: &App, area: Rect) {
 
     let paragraph = Paragraph::new(content).style(style);
     frame.render_widget(paragraph, area);
+
+    // Hidden while a command or filter is being typed
+    if app.is_paused() && app.mode == Mode::Normal {
+        let label = " PAUSED  z:resume  .:step ";
+        let width = (label.len() as u16).min(area.width);
+        let label_area = Rect {
+            x: area.x + area.width - width,
+            width,
+            ..area
+        };
+        let indicator = Paragraph::new(label).style(Style::default().bg(Color::Yellow).fg(Color::Black).bold());
+        frame.render_widget(indicator, label_area);
+    }
 }
 
-fn draw_kill_confirm(frame: &mut Frame, app: &App) {
-    let area = centered_rect(50, 25, frame.area());
+fn draw_signal_menu(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 70, frame.area());
+
+    let mut lines = vec![
+        Line::from(Span::styled("Send Signal", Style::default().bold().fg(Color::Red))),
+        Line::from(""),
+    ];
+    lines.extend(target_lines(&app.kill_targets, app.kill_subtree));
+    lines.push(Line::from(""));
+
+    let entries = signal::MENU
+        .iter()
+        .map(|sig| (format!("{:<5}", sig.name), sig.description.to_string()))
+        .chain(std::iter::once((
+            "TERM, then KILL".to_string(),
+            format!("if still alive after {}", format_uptime(app.kill_grace.as_secs())),
+        )));
+    for (i, (label, description)) in entries.enumerate() {
+        let selected = i == app.signal_menu_index;
+        let style = if selected {
+            Style::default().bg(Color::DarkGray).fg(Color::White)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} {} ", if selected { ">" } else { " " }, label), style.bold()),
+            Span::styled(format!(" {} ", description), style.fg(Color::Gray)),
+        ]));
+    }
 
-    let pid = app.kill_target_pid.unwrap_or(0);
-    let name = app.kill_target_name.as_deref().unwrap_or("unknown");
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "j/k to choose, Enter to send, Esc to cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]);
+
+    let block = Block::default()
+        .title(" Signal ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_export_confirm(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 50, frame.area());
+
+    let path = app
+        .export_path
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let targets: Vec<(ProcessKey, String)> = app
+        .export_targets()
+        .into_iter()
+        .map(|p| (p.key(), p.name.clone()))
+        .collect();
+
+    let mut lines = vec![
+        Line::from(Span::styled("Export Processes?", Style::default().bold().fg(Color::Cyan))),
+        Line::from(""),
+        Line::from(format!("To: {}", path)),
+        Line::from(""),
+    ];
+    if app.tagged_processes.is_empty() {
+        lines.push(Line::from(format!("All {} processes shown in the table", targets.len())));
+    } else {
+        lines.extend(target_lines(&targets, false));
+    }
+    lines.extend([
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[Y]", Style::default().fg(Color::Green).bold()),
+            Span::raw("es  "),
+            Span::styled("[N]", Style::default().fg(Color::Red).bold()),
+            Span::raw("o"),
+        ]),
+    ]);
+
+    let block = Block::default()
+        .title(" Confirm Export ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .alignment(Alignment::Center);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_delete_confirm(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 25, frame.area());
+
+    let path = app
+        .delete_target
+        .as_ref()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
 
     let lines = vec![
         Line::from(Span::styled(
-            "Kill Process?",
+            "Delete Path?",
             Style::default().bold().fg(Color::Red),
         )),
         Line::from(""),
-        Line::from(format!("Process: {}", name)),
-        Line::from(format!("PID: {}", pid)),
+        Line::from(path),
         Line::from(""),
-        Line::from("This will forcefully terminate the process (SIGKILL)."),
+        Line::from("This will permanently remove the file or directory tree."),
         Line::from(""),
         Line::from(vec![
             Span::styled("[Y]", Style::default().fg(Color::Green).bold()),
@@ -102,7 +250,7 @@ fn draw_kill_confirm(frame: &mut Frame, app: &App) {
     ];
 
     let block = Block::default()
-        .title(" Confirm Kill ")
+        .title(" Confirm Delete ")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Red))
         .style(Style::default().bg(Color::Black));
@@ -116,12 +264,159 @@ fn draw_kill_confirm(frame: &mut Frame, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
+/// Describes the processes a popup acts on: one process, a subtree (whose
+/// root comes last), or a list of tagged processes.
+fn target_lines(targets: &[(ProcessKey, String)], subtree: bool) -> Vec<Line<'static>> {
+    const MAX_LISTED: usize = 8;
+    let emphasis = Style::default().fg(Color::Yellow).bold();
+
+    match targets {
+        [] => Vec::new(),
+        [(key, name)] => vec![Line::from(format!("Process: {} (PID: {})", name, key.pid))],
+        [.., (key, name)] if subtree => vec![
+            Line::from(format!("Process: {} (PID: {})", name, key.pid)),
+            Line::from(Span::styled(format!("and its {} descendant processes", targets.len() - 1), emphasis)),
+        ],
+        _ => {
+            let mut lines = vec![Line::from(Span::styled(format!("{} tagged processes:", targets.len()), emphasis))];
+            for (key, name) in targets.iter().take(MAX_LISTED) {
+                lines.push(Line::from(format!("{} ({})", name, key.pid)));
+            }
+            if targets.len() > MAX_LISTED {
+                lines.push(Line::from(Span::styled(
+                    format!("... and {} more", targets.len() - MAX_LISTED),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            lines
+        }
+    }
+}
+
+fn draw_tuning_popup(frame: &mut Frame, tuning: &ProcessTuning) {
+    let area = centered_rect(50, 70, frame.area());
+    let selected = Style::default().bg(Color::DarkGray).fg(Color::White);
+    let hint_style = Style::default().fg(Color::DarkGray);
+
+    let mut lines = target_lines(&tuning.targets, false);
+    lines.push(Line::from(""));
+
+    let (title, hint) = match &tuning.setting {
+        Tuning::Nice { current, new } => {
+            lines.push(Line::from(format!("Current nice: {}", current)));
+            lines.push(Line::from(vec![
+                Span::raw("New nice:     "),
+                Span::styled(format!(" < {} > ", new), selected),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "-20 gets the most CPU, 19 the least; going below the current value needs root",
+                hint_style,
+            )));
+            (" Renice ", "h/l or -/+ to change, Enter to apply, Esc to cancel")
+        }
+        Tuning::IoPriority { current, new } => {
+            lines.push(Line::from(format!("Current I/O priority: {}", current.describe())));
+            lines.push(Line::from(""));
+            for class in IoClass::all() {
+                let label = if class == new.class && class.has_level() {
+                    format!(" {:<12} < {} > ", class.name(), new.level)
+                } else {
+                    format!(" {:<12} ", class.name())
+                };
+                let style = if class == new.class { selected } else { Style::default() };
+                lines.push(Line::from(Span::styled(label, style)));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Level 0 is served first and 7 last; realtime needs root",
+                hint_style,
+            )));
+            (" I/O Priority ", "j/k class, h/l level, Enter to apply, Esc to cancel")
+        }
+        Tuning::Affinity { current, new, cursor } => {
+            lines.push(Line::from(format!("Current CPUs: {}", sched::format_cpu_list(current))));
+            lines.push(Line::from(format!("New CPUs:     {}", sched::format_cpu_list(new))));
+            lines.push(Line::from(""));
+
+            // Lay the cores out in rows of eight
+            for (row, chunk) in new.chunks(8).enumerate() {
+                let spans: Vec<Span> = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, on)| {
+                        let cpu = row * 8 + i;
+                        let label = format!(" [{}] {:<3}", if *on { "x" } else { " " }, cpu);
+                        if cpu == *cursor {
+                            Span::styled(label, selected)
+                        } else if *on {
+                            Span::styled(label, Style::default().fg(Color::Green))
+                        } else {
+                            Span::raw(label)
+                        }
+                    })
+                    .collect();
+                lines.push(Line::from(spans));
+            }
+            (" CPU Affinity ", "h/l to move, Space to toggle, a for all, Enter to apply, Esc to cancel")
+        }
+    };
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(hint, hint_style)));
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_column_chooser(frame: &mut Frame, app: &App) {
+    let area = centered_rect(40, 70, frame.area());
+
+    let mut lines = vec![
+        Line::from(Span::styled("Process Columns", Style::default().bold().fg(Color::Cyan))),
+        Line::from(""),
+    ];
+    for (i, column) in ProcessColumn::all().into_iter().enumerate() {
+        let checkbox = if app.process_columns.contains(&column) { "[x]" } else { "[ ]" };
+        let style = if i == app.column_chooser_index {
+            Style::default().bg(Color::DarkGray).fg(Color::White)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!(" {} {}", checkbox, column.name()), style)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "j/k to move, Space to toggle, Esc or q to close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .title(" Columns ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
 fn draw_device_popup(frame: &mut Frame, app: &App) {
     let area = centered_rect(70, 60, frame.area());
 
     if let Some(idx) = app.selected_device_index {
         if let Some(device) = app.system_data.devices.get(idx) {
-            let lines = vec![
+            let mut lines = vec![
                 Line::from(vec![
                     Span::styled("Device Details", Style::default().bold().fg(Color::Cyan)),
                 ]),
@@ -162,13 +457,19 @@ fn draw_device_popup(frame: &mut Frame, app: &App) {
                     Span::styled("State:      ", Style::default().fg(Color::Yellow)),
                     Span::raw(device.state.as_deref().unwrap_or("-")),
                 ]),
-                Line::from(""),
-                Line::from(Span::styled(
-                    "Press Enter, Esc, or q to close",
-                    Style::default().fg(Color::DarkGray),
-                )),
             ];
 
+            if device.subsystem == "block" && device.device_type == "disk" {
+                lines.push(Line::from(""));
+                lines.extend(smart_lines(app.system_data.smart.get(&device.name)));
+            }
+
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Press Enter, Esc, or q to close",
+                Style::default().fg(Color::DarkGray),
+            )));
+
             let block = Block::default()
                 .title(format!(" {} ", device.name))
                 .borders(Borders::ALL)
@@ -185,6 +486,182 @@ fn draw_device_popup(frame: &mut Frame, app: &App) {
     }
 }
 
+fn draw_network_popup(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 70, frame.area());
+
+    let Some(net) = app
+        .selected_network_index
+        .and_then(|idx| app.system_data.network_list.get(idx))
+    else {
+        return;
+    };
+    let link = &net.link;
+
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Yellow)),
+            Span::raw(value),
+        ])
+    };
+    let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Interface Details", Style::default().bold().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+        field("Name:       ", net.name.clone()),
+        field("Type:       ", link.kind.clone()),
+        field("Driver:     ", or_dash(link.driver.clone())),
+        field("MAC:        ", or_dash(link.mac.clone())),
+        field("MTU:        ", or_dash(link.mtu.map(|m| m.to_string()))),
+        field("State:      ", link.operstate.clone()),
+        field(
+            "Carrier:    ",
+            or_dash(link.carrier.map(|c| if c { "yes" } else { "no" }.to_string())),
+        ),
+        field("Speed:      ", or_dash(link.speed.map(|s| format!("{} Mb/s", s)))),
+        field("Duplex:     ", or_dash(link.duplex.clone())),
+        Line::from(""),
+    ];
+
+    lines.push(field(
+        "IPv4:       ",
+        if link.ipv4.is_empty() { "-".to_string() } else { link.ipv4.join(", ") },
+    ));
+    lines.push(field(
+        "IPv6:       ",
+        if link.ipv6.is_empty() { "-".to_string() } else { link.ipv6.join(", ") },
+    ));
+
+    if let Some(wifi) = &net.wireless {
+        let dbm = |value: Option<f32>| or_dash(value.map(|v| format!("{:.0} dBm", v)));
+        lines.push(Line::from(""));
+        lines.push(field("SSID:       ", or_dash(wifi.ssid.clone())));
+        lines.push(field("Frequency:  ", or_dash(wifi.frequency.map(|f| format!("{} MHz", f)))));
+        lines.push(field("Bitrate:    ", or_dash(wifi.bitrate.map(|b| format!("{:.1} Mb/s", b)))));
+        lines.push(Line::from(vec![
+            Span::styled("Signal:     ", Style::default().fg(Color::Yellow)),
+            Span::styled(dbm(wifi.signal_dbm), widgets::network::signal_style(wifi.signal_dbm)),
+        ]));
+        lines.push(field("Noise:      ", dbm(wifi.noise_dbm)));
+        lines.push(field(
+            "Quality:    ",
+            format!("{:.0}/{:.0}", wifi.quality, WirelessInfo::QUALITY_MAX),
+        ));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(field(
+        "RX:         ",
+        format!(
+            "{}  peak {}  total {}",
+            widgets::network::format_rate(net.rx_rate),
+            widgets::network::format_rate(net.peak_rx_rate),
+            format_bytes(net.received)
+        ),
+    ));
+    lines.push(field(
+        "TX:         ",
+        format!(
+            "{}  peak {}  total {}",
+            widgets::network::format_rate(net.tx_rate),
+            widgets::network::format_rate(net.peak_tx_rate),
+            format_bytes(net.transmitted)
+        ),
+    ));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press Enter, Esc, or q to close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let block = Block::default()
+        .title(format!(" {} ", net.name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+fn smart_lines(smart: Option<&SmartInfo>) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        "SMART Health",
+        Style::default().bold().fg(Color::Cyan),
+    ))];
+
+    let Some(smart) = smart else {
+        lines.push(Line::from(Span::styled(
+            "No SMART data (smartctl missing or needs root)",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    };
+
+    let field = |label: &'static str, value: Option<String>, bad: bool| {
+        let style = if bad {
+            Style::default().fg(Color::Red).bold()
+        } else {
+            Style::default()
+        };
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Yellow)),
+            Span::styled(value.unwrap_or_else(|| "-".to_string()), style),
+        ])
+    };
+
+    lines.push(field(
+        "Health:     ",
+        smart.passed.map(|p| if p { "PASSED" } else { "FAILED" }.to_string()),
+        smart.passed == Some(false),
+    ));
+    lines.push(field(
+        "Temp:       ",
+        smart.temperature.map(|t| format!("{}°C", t)),
+        smart.temperature.is_some_and(|t| t >= 60),
+    ));
+    lines.push(field(
+        "Power On:   ",
+        smart.power_on_hours.map(|h| format!("{} h", h)),
+        false,
+    ));
+
+    if smart.reallocated_sectors.is_some() || smart.pending_sectors.is_some() {
+        lines.push(field(
+            "Realloc:    ",
+            smart.reallocated_sectors.map(|n| n.to_string()),
+            smart.reallocated_sectors.is_some_and(|n| n > 0),
+        ));
+        lines.push(field(
+            "Pending:    ",
+            smart.pending_sectors.map(|n| n.to_string()),
+            smart.pending_sectors.is_some_and(|n| n > 0),
+        ));
+    }
+
+    if smart.percentage_used.is_some() || smart.media_errors.is_some() {
+        lines.push(field(
+            "Wear:       ",
+            smart.percentage_used.map(|n| format!("{}% used", n)),
+            smart.percentage_used.is_some_and(|n| n >= 90),
+        ));
+        lines.push(field(
+            "Media Errs: ",
+            smart.media_errors.map(|n| n.to_string()),
+            smart.media_errors.is_some_and(|n| n > 0),
+        ));
+    }
+
+    lines
+}
+
 fn draw_help(frame: &mut Frame) {
     let area = centered_rect(60, 70, frame.area());
 
@@ -196,7 +673,12 @@ fn draw_help(frame: &mut Frame) {
         Line::from("  c           CPU"),
         Line::from("  m           Memory"),
         Line::from("  d           Disks"),
+        Line::from("  b           Storage (RAID/LVM/dm)"),
         Line::from("  n           Network"),
+        Line::from("  o           Connections"),
+        Line::from("  r           Routing, neighbors and DNS"),
+        Line::from("  u           Unix sockets and IPC"),
+        Line::from("  f           Firewall rules"),
         Line::from("  p           Processes"),
         Line::from("  v           Devices"),
         Line::from("  l           Logs"),
@@ -205,15 +687,43 @@ fn draw_help(frame: &mut Frame) {
         Line::from("  Tab         Switch focus between panes"),
         Line::from("  Up/k        Move up / Select previous item"),
         Line::from("  Down/j      Move down / Select next item"),
+        Line::from("  PgUp/PgDn   Move a page up / down"),
+        Line::from("  g/Home      Jump to first item"),
+        Line::from("  G/End       Jump to last item"),
         Line::from("  Enter       Open selected item / View details"),
+        Line::from("  Backspace   Go up a directory (in Disk Usage)"),
         Line::from(""),
         Line::from("Vim Commands:").style(Style::default().bold()),
         Line::from("  :q          Quit application"),
         Line::from("  :help       Show this help"),
+        Line::from("  :horizon 6h Flag disks predicted to fill within 6h"),
+        Line::from("  :grace 10s  After TERM, wait 10s then send KILL if still alive"),
+        Line::from("              (a bare number is seconds here, hours for :horizon)"),
+        Line::from("  :export f   Export tagged (or shown) processes to CSV"),
+        Line::from("  :pause      Freeze / resume the data on screen"),
         Line::from(""),
         Line::from("Actions:").style(Style::default().bold()),
         Line::from("  w           Close current tab"),
-        Line::from("  x/Delete    Kill selected process (in Processes)"),
+        Line::from("  z           Pause / resume refreshing the display"),
+        Line::from("  .           Show the next refresh while paused"),
+        Line::from("  x/Delete    Send a signal to selected process (in Processes)"),
+        Line::from("              Delete selected path (in Disk Usage)"),
+        Line::from("  s           Cycle sort column (in Processes)"),
+        Line::from("  S           Reverse sort direction (in Processes)"),
+        Line::from("  F2          Choose visible columns (in Processes)"),
+        Line::from("  e/i/a       Renice / ionice / CPU affinity (in Processes)"),
+        Line::from("  H           Show / hide threads of process (in Processes)"),
+        Line::from("  Space       Tag process; x/e/i/a then act on all tagged"),
+        Line::from("  T           Tag all matching the filter / clear tags"),
+        Line::from("  /           Filter processes: text, user:, state:, pid:,"),
+        Line::from("              cpu>10, mem>1G (Enter keeps, Esc clears)"),
+        Line::from("  t           Toggle process tree (in Processes)"),
+        Line::from("  -/+         Collapse / expand subtree (in tree view)"),
+        Line::from("  K           Signal process and its descendants (in Processes)"),
+        Line::from("  s/i         Listening / this process only (in Connections)"),
+        Line::from("  Enter       Explore directory sizes (in Disks)"),
+        Line::from("              Show process details (in Processes)"),
+        Line::from("              Go to owning process (in Connections, IPC)"),
         Line::from("  ?           Toggle this help"),
         Line::from(""),
         Line::from("Press ? or Esc to close").style(Style::default().fg(Color::DarkGray)),
@@ -10,10 +10,27 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
 
     // Draw active tab content directly (no tab bar)
     if let Some(tab) = app.active_tab() {
+        let title = match tab.node {
+            TreeNode::Processes => {
+                let mut title = format!(" {} ", tab.node.name());
+                if !app.process_filter.is_empty() {
+                    title.push_str(&format!("[filter: {}] ", app.process_filter));
+                }
+                if !app.tagged_processes.is_empty() {
+                    title.push_str(&format!("[{} tagged] ", app.tagged_processes.len()));
+                }
+                title
+            }
+            TreeNode::ProcessDetail => match &app.process_detail {
+                Some(detail) => format!(" {}: {} ({}) ", tab.node.name(), detail.name, detail.key.pid),
+                None => format!(" {} ", tab.node.name()),
+            },
+            _ => format!(" {} ", tab.node.name()),
+        };
         let content_block = Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(format!(" {} ", tab.node.name()));
+            .title(title);
 
         let inner_area = content_block.inner(area);
         frame.render_widget(content_block, area);
@@ -22,11 +39,18 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             TreeNode::Overview => widgets::overview::draw(frame, app, inner_area),
             TreeNode::Cpu => widgets::cpu::draw(frame, app, inner_area),
             TreeNode::Memory => widgets::memory::draw(frame, app, inner_area),
-            TreeNode::Disks => widgets::disk::draw(frame, app, inner_area),
-            TreeNode::Network => widgets::network::draw(frame, app, inner_area),
-            TreeNode::Processes => widgets::processes::draw(frame, app, inner_area, tab.scroll_offset, tab.selected_item),
-            TreeNode::Devices => widgets::devices::draw(frame, app, inner_area, tab.selected_item),
-            TreeNode::Logs => widgets::logs::draw(frame, app, inner_area, tab.scroll_offset),
+            TreeNode::Disks => widgets::disk::draw(frame, app, inner_area, tab),
+            TreeNode::Storage => widgets::storage::draw(frame, app, inner_area, tab),
+            TreeNode::Network => widgets::network::draw(frame, app, inner_area, tab),
+            TreeNode::Connections => widgets::connections::draw(frame, app, inner_area, tab),
+            TreeNode::Routing => widgets::netconfig::draw(frame, app, inner_area, tab),
+            TreeNode::Ipc => widgets::ipc::draw(frame, app, inner_area, tab),
+            TreeNode::Firewall => widgets::firewall::draw(frame, app, inner_area, tab),
+            TreeNode::Processes => widgets::processes::draw(frame, app, inner_area, tab),
+            TreeNode::Devices => widgets::devices::draw(frame, app, inner_area, tab),
+            TreeNode::Logs => widgets::logs::draw(frame, app, inner_area, tab),
+            TreeNode::DiskUsage => widgets::diskusage::draw(frame, app, inner_area, tab),
+            TreeNode::ProcessDetail => widgets::procdetail::draw(frame, app, inner_area, tab),
         }
     }
 }
@@ -0,0 +1,135 @@
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Paragraph, Row, Table},
+};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let connections = app.visible_connections();
+
+    let mut filters = Vec::new();
+    if app.connections_listening_only {
+        filters.push("listening only".to_string());
+    }
+    if let Some(pid) = app.connections_pid_filter {
+        filters.push(format!("PID {}", pid));
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    let summary = if filters.is_empty() {
+        format!("{} sockets", connections.len())
+    } else {
+        format!("{} sockets ({})", connections.len(), filters.join(", "))
+    };
+    frame.render_widget(
+        Paragraph::new(summary).style(Style::default().fg(Color::DarkGray)),
+        chunks[0],
+    );
+
+    if connections.is_empty() {
+        let paragraph = Paragraph::new("No sockets match the current filters");
+        frame.render_widget(paragraph, chunks[1]);
+        scroll::clear(tab);
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("Proto").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Local Address").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Remote Address").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("State").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Recv-Q").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Send-Q").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Process").style(Style::default().bold().fg(Color::Cyan)),
+    ])
+    .height(1);
+
+    // Keep the selection on screen
+    let visible_height = chunks[1].height.saturating_sub(2) as usize;
+    let visible = scroll::visible_rows(tab, connections.len(), visible_height);
+
+    let rows: Vec<Row> = connections
+        .iter()
+        .enumerate()
+        .skip(visible.start)
+        .take(visible.len())
+        .map(|(i, conn)| {
+            let style = if i == tab.selected_item {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+
+            let state_style = match conn.state {
+                "LISTEN" | "UNCONN" => Style::default().fg(Color::Green),
+                "ESTAB" => Style::default().fg(Color::Cyan),
+                "CLOSE-WAIT" | "TIME-WAIT" | "FIN-WAIT-1" | "FIN-WAIT-2" => Style::default().fg(Color::Yellow),
+                _ => Style::default(),
+            };
+            let queue_style = |q: u64| {
+                if q > 0 {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                }
+            };
+
+            let process = match (&conn.process, conn.pid) {
+                (Some(name), Some(pid)) => format!("{} ({})", name, pid),
+                (None, Some(pid)) => pid.to_string(),
+                _ => "-".to_string(),
+            };
+
+            Row::new(vec![
+                Cell::from(conn.protocol),
+                Cell::from(conn.local.clone()),
+                Cell::from(conn.remote.clone()),
+                Cell::from(conn.state).style(state_style),
+                Cell::from(conn.recv_queue.to_string()).style(queue_style(conn.recv_queue)),
+                Cell::from(conn.send_queue.to_string()).style(queue_style(conn.send_queue)),
+                Cell::from(process),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Length(28),
+        Constraint::Length(28),
+        Constraint::Length(11),
+        Constraint::Length(7),
+        Constraint::Length(7),
+        Constraint::Min(15),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray));
+
+    frame.render_widget(table, chunks[1]);
+    let rows_area = Rect {
+        y: chunks[1].y + 1,
+        height: visible_height as u16,
+        ..chunks[1]
+    };
+    scroll::draw_scrollbar(frame, rows_area, connections.len(), visible.start, visible_height);
+
+    // Show hint at bottom
+    let hint = Paragraph::new(" Enter:go to process | s:listening only | i:this process only | j/k:navigate ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    let hint_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1),
+        width: area.width,
+        height: 1,
+    };
+    frame.render_widget(hint, hint_area);
+}
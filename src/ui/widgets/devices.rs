@@ -1,15 +1,17 @@
-use crate::app::App;
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
 use ratatui::{
     prelude::*,
     widgets::{Cell, Paragraph, Row, Table},
 };
 
-pub fn draw(frame: &mut Frame, app: &App, area: Rect, selected_item: usize) {
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
     let devices = &app.system_data.devices;
 
     if devices.is_empty() {
         let paragraph = Paragraph::new("No devices found. Try running with appropriate permissions.");
         frame.render_widget(paragraph, area);
+        scroll::clear(tab);
         return;
     }
 
@@ -23,11 +25,17 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect, selected_item: usize) {
     ])
     .height(1);
 
+    // Rows between the header and the hint
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let visible = scroll::visible_rows(tab, devices.len(), visible_height);
+
     let rows: Vec<Row> = devices
         .iter()
         .enumerate()
+        .skip(visible.start)
+        .take(visible.len())
         .map(|(i, dev)| {
-            let style = if i == selected_item {
+            let style = if i == tab.selected_item {
                 Style::default().bg(Color::DarkGray).fg(Color::White)
             } else {
                 Style::default()
@@ -65,9 +73,15 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect, selected_item: usize) {
         .row_highlight_style(Style::default().bg(Color::DarkGray));
 
     frame.render_widget(table, area);
+    let rows_area = Rect {
+        y: area.y + 1,
+        height: visible_height as u16,
+        ..area
+    };
+    scroll::draw_scrollbar(frame, rows_area, devices.len(), visible.start, visible_height);
 
     // Show hint at bottom
-    let hint = Paragraph::new(" Press Enter to view device details | j/k to navigate ")
+    let hint = Paragraph::new(" Press Enter to view device details | j/k to navigate")
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
 
@@ -1,26 +1,28 @@
-use crate::app::App;
-use crate::system::format_bytes;
+use crate::app::{App, Tab};
+use crate::system::{format_bytes, format_uptime};
+use crate::ui::widgets::scroll;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Gauge},
 };
 
-pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
     let disks = &app.system_data.disk_list;
 
     if disks.is_empty() {
         let paragraph = ratatui::widgets::Paragraph::new("No disk information available");
         frame.render_widget(paragraph, area);
+        scroll::clear(tab);
         return;
     }
 
-    // Calculate max items that fit
+    // Calculate max items that fit (last line is reserved for the hint)
     let item_height = 4;
-    let max_items = (area.height as usize / item_height).max(1);
+    let max_items = (area.height.saturating_sub(1) as usize / item_height).max(1);
+    let visible = scroll::visible_rows(tab, disks.len(), max_items);
 
-    let constraints: Vec<Constraint> = disks
-        .iter()
-        .take(max_items)
+    let constraints: Vec<Constraint> = visible
+        .clone()
         .map(|_| Constraint::Length(item_height as u16))
         .chain(std::iter::once(Constraint::Min(0)))
         .collect();
@@ -30,7 +32,8 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
         .constraints(constraints)
         .split(area);
 
-    for (i, disk) in disks.iter().take(max_items).enumerate() {
+    for (chunk, i) in chunks.iter().zip(visible.clone()) {
+        let disk = &disks[i];
         let used = disk.total.saturating_sub(disk.available);
         let percent = if disk.total > 0 {
             ((used as f64 / disk.total as f64) * 100.0) as u16
@@ -44,8 +47,39 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             disk.file_system
         );
 
+        let title_style = if i == tab.selected_item {
+            Style::default().bg(Color::DarkGray).fg(Color::White).bold()
+        } else {
+            Style::default()
+        };
+
+        // Fill rate and estimated time until full
+        let mut title_spans = vec![Span::styled(title, title_style)];
+        if let Some(rate) = disk.fill_rate {
+            let sign = if rate < 0.0 { "-" } else { "+" };
+            title_spans.push(Span::styled(
+                format!("  {}{}/s", sign, format_bytes(rate.abs() as u64)),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if let Some(eta) = disk.time_to_full {
+            let style = if eta <= app.disk_full_horizon {
+                Style::default().fg(Color::Red).bold()
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            title_spans.push(Span::styled(
+                format!("  full in {}", format_uptime(eta.as_secs())),
+                style,
+            ));
+        }
+
         let gauge = Gauge::default()
-            .block(Block::default().title(title).borders(Borders::NONE))
+            .block(
+                Block::default()
+                    .title(Line::from(title_spans))
+                    .borders(Borders::NONE),
+            )
             .gauge_style(
                 Style::default()
                     .fg(usage_color(percent))
@@ -59,14 +93,14 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
                 percent
             ));
 
-        frame.render_widget(gauge, chunks[i]);
+        frame.render_widget(gauge, *chunk);
     }
 
-    // Show count if there are more
-    if disks.len() > max_items {
+    // Show count if there are more below
+    if disks.len() > visible.end {
         let more_text = ratatui::widgets::Paragraph::new(format!(
             "... and {} more disk(s)",
-            disks.len() - max_items
+            disks.len() - visible.end
         ))
         .style(Style::default().fg(Color::DarkGray));
 
@@ -74,6 +108,25 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             frame.render_widget(more_text, *last_chunk);
         }
     }
+
+    let cards_area = Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    scroll::draw_scrollbar(frame, cards_area, disks.len(), visible.start, max_items);
+
+    // Show hint at bottom
+    let hint = ratatui::widgets::Paragraph::new(" Press Enter to explore directory sizes | j/k to navigate ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    let hint_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1),
+        width: area.width,
+        height: 1,
+    };
+    frame.render_widget(hint, hint_area);
 }
 
 fn usage_color(percent: u16) -> Color {
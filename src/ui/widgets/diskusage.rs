@@ -0,0 +1,145 @@
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
+use crate::system::format_bytes;
+use ratatui::{
+    prelude::*,
+    widgets::{Cell, Paragraph, Row, Table},
+};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let Some(explorer) = &app.disk_explorer else {
+        let paragraph = Paragraph::new("Select a mount in the Disks view and press Enter to scan it");
+        frame.render_widget(paragraph, area);
+        scroll::clear(tab);
+        return;
+    };
+
+    let Some(dir) = explorer.current() else {
+        let paragraph = Paragraph::new(format!(
+            "Scanning {} ... {} entries",
+            explorer.root_path.display(),
+            explorer.scanned
+        ))
+        .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(paragraph, area);
+        scroll::clear(tab);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(area);
+
+    let summary = Paragraph::new(Line::from(vec![
+        Span::styled(dir.path.to_string_lossy().to_string(), Style::default().bold().fg(Color::Cyan)),
+        Span::raw(format!("  Total: {}  Items: {}", format_bytes(dir.size), dir.children.len())),
+    ]));
+    frame.render_widget(summary, chunks[0]);
+
+    if dir.children.is_empty() {
+        let paragraph = Paragraph::new("Empty directory");
+        frame.render_widget(paragraph, chunks[1]);
+        scroll::clear(tab);
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("Size").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("%").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Usage").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Name").style(Style::default().bold().fg(Color::Cyan)),
+    ])
+    .height(1);
+
+    // Keep the selection on screen
+    let visible_height = chunks[1].height.saturating_sub(2) as usize;
+    let visible = scroll::visible_rows(tab, dir.children.len(), visible_height);
+
+    let rows: Vec<Row> = dir
+        .children
+        .iter()
+        .enumerate()
+        .skip(visible.start)
+        .take(visible.len())
+        .map(|(i, entry)| {
+            let percent = if dir.size > 0 {
+                entry.size as f64 / dir.size as f64 * 100.0
+            } else {
+                0.0
+            };
+
+            let style = if i == tab.selected_item {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+
+            let name = if entry.is_dir {
+                format!("{}/", entry.name)
+            } else {
+                entry.name.clone()
+            };
+            let name_style = if entry.is_dir {
+                Style::default().fg(Color::Cyan)
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(format_bytes(entry.size)),
+                Cell::from(format!("{:.1}", percent)),
+                Cell::from(percent_bar(percent, 20)).style(Style::default().fg(usage_color(percent))),
+                Cell::from(name).style(name_style),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(12),
+        Constraint::Length(6),
+        Constraint::Length(22),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(Style::default().bg(Color::DarkGray));
+
+    frame.render_widget(table, chunks[1]);
+    let rows_area = Rect {
+        y: chunks[1].y + 1,
+        height: visible_height as u16,
+        ..chunks[1]
+    };
+    scroll::draw_scrollbar(frame, rows_area, dir.children.len(), visible.start, visible_height);
+
+    // Show hint at bottom
+    let hint = Paragraph::new(" Enter:open | Backspace:up | x:delete | j/k:navigate ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    let hint_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1),
+        width: area.width,
+        height: 1,
+    };
+    frame.render_widget(hint, hint_area);
+}
+
+fn percent_bar(percent: f64, width: usize) -> String {
+    let filled = ((percent / 100.0) * width as f64).round() as usize;
+    format!("[{}{}]", "#".repeat(filled.min(width)), " ".repeat(width - filled.min(width)))
+}
+
+fn usage_color(percent: f64) -> Color {
+    if percent >= 50.0 {
+        Color::Red
+    } else if percent >= 25.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
@@ -0,0 +1,115 @@
+use crate::app::{App, Tab};
+use crate::system::format_bytes;
+use crate::system::firewall::Chain;
+use crate::ui::widgets::scroll;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let ruleset = &app.system_data.firewall;
+
+    if let Some(error) = &ruleset.error {
+        let lines = vec![
+            Line::from(Span::styled("Could not read the firewall ruleset", Style::default().bold())),
+            Line::from(""),
+            Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Listing rules needs root and either nft or iptables-save",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(lines), area);
+        scroll::clear(tab);
+        return;
+    }
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{} ruleset", ruleset.backend), Style::default().bold()),
+            Span::styled(
+                format!("  {} tables, {} rules", ruleset.tables.len(), ruleset.rule_count()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(Span::styled(
+            format!("  {:>10} {:>10}  Rule", "Packets", "Bytes"),
+            Style::default().bold().fg(Color::Cyan),
+        )),
+    ];
+
+    for table in &ruleset.tables {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("table ", Style::default().bold()),
+            Span::styled(format!("{} {}", table.family, table.name), Style::default().bold().fg(Color::Cyan)),
+        ]));
+
+        for chain in &table.chains {
+            lines.push(chain_line(chain));
+
+            if chain.rules.is_empty() {
+                lines.push(Line::from(Span::styled("      (no rules)", Style::default().fg(Color::DarkGray))));
+            }
+            for rule in &chain.rules {
+                // Rules that have matched stand out from the ones that never fired
+                let counter_style = match rule.packets {
+                    Some(0) | None => Style::default().fg(Color::DarkGray),
+                    Some(_) => Style::default().fg(Color::Green),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "  {:>10} {:>10}  ",
+                            rule.packets.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string()),
+                            rule.bytes.map(format_bytes).unwrap_or_else(|| "-".to_string()),
+                        ),
+                        counter_style,
+                    ),
+                    Span::styled(rule.text.clone(), verdict_style(&rule.text)),
+                ]));
+            }
+        }
+    }
+
+    let len = lines.len();
+    let offset = scroll::page_offset(tab, len, area.height as usize);
+    let paragraph = Paragraph::new(lines).scroll((offset as u16, 0));
+    frame.render_widget(paragraph, area);
+    scroll::draw_scrollbar(frame, area, len, offset, area.height as usize);
+}
+
+fn chain_line(chain: &Chain) -> Line<'static> {
+    let mut spans = vec![
+        Span::raw("  chain "),
+        Span::styled(chain.name.clone(), Style::default().fg(Color::Yellow).bold()),
+    ];
+
+    if let Some(hook) = &chain.hook {
+        spans.push(Span::styled(format!("  hook {}", hook), Style::default().fg(Color::DarkGray)));
+    }
+    if let Some(policy) = &chain.policy {
+        spans.push(Span::raw("  policy "));
+        spans.push(Span::styled(policy.clone(), verdict_style(policy)));
+    }
+    if let (Some(packets), Some(bytes)) = (chain.packets, chain.bytes) {
+        spans.push(Span::styled(
+            format!("  [{} packets, {}]", packets, format_bytes(bytes)),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    Line::from(spans)
+}
+
+/// Colors a rule or policy by its verdict, for both nft and iptables syntax.
+fn verdict_style(text: &str) -> Style {
+    let text = text.to_lowercase();
+
+    if text.contains("drop") || text.contains("reject") {
+        Style::default().fg(Color::Red)
+    } else if text.contains("accept") {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    }
+}
@@ -0,0 +1,180 @@
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
+use crate::system::format_bytes;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    draw_unix_sockets(frame, app, chunks[0], tab);
+    draw_ipc_objects(frame, app, chunks[1]);
+
+    // Show hint at bottom
+    let hint = Paragraph::new(" Enter:go to process | j/k:navigate ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    let hint_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(1),
+        width: area.width,
+        height: 1,
+    };
+    frame.render_widget(hint, hint_area);
+}
+
+fn draw_unix_sockets(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let sockets = &app.system_data.ipc.unix_sockets;
+    let listening = sockets.iter().filter(|s| s.is_listening()).count();
+
+    let block = Block::default()
+        .title(format!(" Unix Sockets ({}, {} listening) ", sockets.len(), listening))
+        .borders(Borders::BOTTOM);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let header = Row::new(vec![
+        Cell::from("Type").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("State").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Inode").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Path").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Process").style(Style::default().bold().fg(Color::Cyan)),
+    ])
+    .height(1);
+
+    // Keep the selection on screen
+    let visible_height = inner.height.saturating_sub(1) as usize;
+    let visible = scroll::visible_rows(tab, sockets.len(), visible_height);
+
+    let rows: Vec<Row> = sockets
+        .iter()
+        .enumerate()
+        .skip(visible.start)
+        .take(visible.len())
+        .map(|(i, sock)| {
+            let style = if i == tab.selected_item {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+
+            let state_style = match sock.state {
+                "LISTEN" => Style::default().fg(Color::Green),
+                "CONNECTED" => Style::default().fg(Color::Cyan),
+                _ => Style::default(),
+            };
+
+            let process = match (&sock.process, sock.pid) {
+                (Some(name), Some(pid)) => format!("{} ({})", name, pid),
+                (None, Some(pid)) => pid.to_string(),
+                _ => "-".to_string(),
+            };
+
+            Row::new(vec![
+                Cell::from(sock.kind),
+                Cell::from(sock.state).style(state_style),
+                Cell::from(sock.inode.to_string()),
+                Cell::from(sock.path.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(process),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(14),
+        Constraint::Length(10),
+        Constraint::Min(30),
+        Constraint::Length(24),
+    ];
+
+    frame.render_widget(Table::new(rows, widths).header(header), inner);
+    let rows_area = Rect {
+        y: inner.y + 1,
+        height: visible_height as u16,
+        ..inner
+    };
+    scroll::draw_scrollbar(frame, rows_area, sockets.len(), visible.start, visible_height);
+}
+
+fn draw_ipc_objects(frame: &mut Frame, app: &App, area: Rect) {
+    let ipc = &app.system_data.ipc;
+    let heading = |title: String| Line::from(Span::styled(title, Style::default().bold()));
+    let none = || Line::from(Span::styled("  (none)", Style::default().fg(Color::DarkGray)));
+    let dim = Style::default().fg(Color::DarkGray);
+
+    let mut lines = Vec::new();
+
+    lines.push(heading(format!("SysV Shared Memory ({}):", ipc.shared_memory.len())));
+    if ipc.shared_memory.is_empty() {
+        lines.push(none());
+    }
+    for shm in &ipc.shared_memory {
+        // Segments nobody has attached are usually leaked
+        let attach_style = if shm.attached == 0 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  key 0x{:08x}  id {:<8}", shm.key, shm.id), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:>10}  ", format_bytes(shm.size))),
+            Span::styled(format!("{} attached", shm.attached), attach_style),
+            Span::styled(
+                format!("  owner {}  perms {:o}  creator {}", app.system_data.user_name(shm.uid), shm.perms, shm.creator_pid),
+                dim,
+            ),
+        ]));
+    }
+
+    lines.push(heading(format!("SysV Semaphores ({}):", ipc.semaphores.len())));
+    if ipc.semaphores.is_empty() {
+        lines.push(none());
+    }
+    for sem in &ipc.semaphores {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  key 0x{:08x}  id {:<8}", sem.key, sem.id), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{} semaphores", sem.count)),
+            Span::styled(format!("  owner {}  perms {:o}", app.system_data.user_name(sem.uid), sem.perms), dim),
+        ]));
+    }
+
+    lines.push(heading(format!("SysV Message Queues ({}):", ipc.message_queues.len())));
+    if ipc.message_queues.is_empty() {
+        lines.push(none());
+    }
+    for msg in &ipc.message_queues {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  key 0x{:08x}  id {:<8}", msg.key, msg.id), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{} messages, {}", msg.messages, format_bytes(msg.bytes))),
+            Span::styled(format!("  owner {}  perms {:o}", app.system_data.user_name(msg.uid), msg.perms), dim),
+        ]));
+    }
+
+    lines.push(heading(format!("POSIX Shared Memory /dev/shm ({}):", ipc.posix.len())));
+    if ipc.posix.is_empty() {
+        lines.push(none());
+    }
+    for obj in &ipc.posix {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<4}", obj.kind), dim),
+            Span::styled(format!("{:<40}", obj.name), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:>10}", format_bytes(obj.size))),
+            Span::styled(format!("  owner {}", app.system_data.user_name(obj.uid)), dim),
+        ]));
+    }
+
+    // Leave the last line for the hint
+    let area = Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    frame.render_widget(Paragraph::new(lines), area);
+}
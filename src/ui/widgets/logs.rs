@@ -1,21 +1,23 @@
-use crate::app::App;
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
 use ratatui::{
     prelude::*,
     widgets::Paragraph,
 };
 
-pub fn draw(frame: &mut Frame, app: &App, area: Rect, scroll_offset: u16) {
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
     let logs = &app.system_data.logs;
 
     if logs.is_empty() {
         let paragraph = Paragraph::new("No log entries available (try running with sudo for dmesg access)");
         frame.render_widget(paragraph, area);
+        scroll::clear(tab);
         return;
     }
 
     // Calculate visible range
     let visible_height = area.height as usize;
-    let offset = scroll_offset as usize;
+    let offset = scroll::page_offset(tab, logs.len(), visible_height);
     let end = (offset + visible_height).min(logs.len());
     let visible_logs = &logs[offset..end];
 
@@ -40,11 +42,12 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect, scroll_offset: u16) {
     let paragraph = Paragraph::new(lines);
 
     frame.render_widget(paragraph, area);
+    scroll::draw_scrollbar(frame, area, logs.len(), offset, visible_height);
 
     // Show scroll indicator
     if logs.len() > visible_height {
         let indicator = format!(
-            " [{}-{}/{}] Use j/k to scroll ",
+            " [{}-{}/{}] j/k:scroll | PgUp/PgDn:page | g/G:top/bottom ",
             offset + 1,
             end,
             logs.len()
@@ -56,7 +59,8 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect, scroll_offset: u16) {
         let indicator_area = Rect {
             x: area.x,
             y: area.y + area.height.saturating_sub(1),
-            width: area.width,
+            // Leave the scrollbar column clear
+            width: area.width.saturating_sub(1),
             height: 1,
         };
         frame.render_widget(indicator_widget, indicator_area);
@@ -2,7 +2,15 @@ pub mod overview;
 pub mod cpu;
 pub mod memory;
 pub mod disk;
+pub mod diskusage;
+pub mod storage;
 pub mod network;
+pub mod connections;
+pub mod netconfig;
+pub mod ipc;
+pub mod firewall;
 pub mod processes;
+pub mod procdetail;
 pub mod devices;
 pub mod logs;
+pub mod scroll;
@@ -0,0 +1,98 @@
+use crate::app::{App, Tab};
+use crate::system::netconfig::Route;
+use crate::ui::widgets::scroll;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let config = &app.system_data.net_config;
+    let heading = |title: &'static str| Line::from(Span::styled(title, Style::default().bold()));
+    let none = || Line::from(Span::styled("  (none)", Style::default().fg(Color::DarkGray)));
+
+    let mut lines = Vec::new();
+
+    for (title, routes) in [("IPv4 Routes:", &config.routes_v4), ("IPv6 Routes:", &config.routes_v6)] {
+        lines.push(heading(title));
+        if routes.is_empty() {
+            lines.push(none());
+        }
+        lines.extend(routes.iter().map(route_line));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(heading("Policy Rules:"));
+    if config.rules.is_empty() {
+        lines.push(none());
+    }
+    for rule in &config.rules {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<7}", rule.priority), Style::default().fg(Color::DarkGray)),
+            Span::raw(format!("{:<40}", rule.selector)),
+            Span::styled(format!("lookup {}", rule.table), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("Neighbors:"));
+    if config.neighbors.is_empty() {
+        lines.push(none());
+    }
+    for neigh in &config.neighbors {
+        let state_style = if neigh.state.contains("REACHABLE") || neigh.state.contains("PERMANENT") {
+            Style::default().fg(Color::Green)
+        } else if neigh.state.contains("FAILED") || neigh.state.contains("INCOMPLETE") {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(Color::Yellow)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<40}", neigh.address), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:<19}", neigh.mac.as_deref().unwrap_or("-"))),
+            Span::raw(format!("{:<10}", neigh.interface)),
+            Span::styled(neigh.state.clone(), state_style),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(heading("DNS Resolver (/etc/resolv.conf):"));
+    let field = |label: &'static str, values: &[String]| {
+        Line::from(vec![
+            Span::styled(label, Style::default().fg(Color::Cyan)),
+            Span::raw(if values.is_empty() { "-".to_string() } else { values.join(", ") }),
+        ])
+    };
+    lines.push(field("  Nameservers: ", &config.nameservers));
+    lines.push(field("  Search:      ", &config.search));
+    lines.push(field("  Options:     ", &config.resolver_options));
+    lines.push(Line::from(""));
+
+    lines.push(heading("Hosts (/etc/hosts):"));
+    if config.hosts.is_empty() {
+        lines.push(none());
+    }
+    for (addr, names) in &config.hosts {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<40}", addr), Style::default().fg(Color::Cyan)),
+            Span::raw(names.clone()),
+        ]));
+    }
+
+    let len = lines.len();
+    let offset = scroll::page_offset(tab, len, area.height as usize);
+    let paragraph = Paragraph::new(lines).scroll((offset as u16, 0));
+    frame.render_widget(paragraph, area);
+    scroll::draw_scrollbar(frame, area, len, offset, area.height as usize);
+}
+
+fn route_line(route: &Route) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!("  {:<40}", route.destination),
+        Style::default().fg(Color::Cyan),
+    )];
+    match &route.gateway {
+        Some(gw) => spans.push(Span::raw(format!("via {:<26}", gw))),
+        None => spans.push(Span::styled(format!("{:<30}", "direct"), Style::default().fg(Color::DarkGray))),
+    }
+    spans.push(Span::raw(format!("dev {:<10}", route.interface)));
+    spans.push(Span::styled(format!("metric {}", route.metric), Style::default().fg(Color::DarkGray)));
+    Line::from(spans)
+}
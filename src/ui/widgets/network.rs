@@ -1,46 +1,319 @@
-use crate::app::App;
+use crate::app::{App, Tab};
 use crate::system::format_bytes;
+use crate::system::netdev::{Counter, WirelessInfo};
+use crate::ui::widgets::scroll;
 use ratatui::{
     prelude::*,
-    widgets::{Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table},
 };
 
-pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
     let networks = &app.system_data.network_list;
+    let selected_item = tab.selected_item;
 
     if networks.is_empty() {
         let paragraph = Paragraph::new("No network interfaces found");
         frame.render_widget(paragraph, area);
+        scroll::clear(tab);
         return;
     }
 
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(4),
+            Constraint::Length(networks.len() as u16 + 2),
+            Constraint::Length(10),
+        ])
+        .split(area);
+
     let header = Row::new(vec![
         Cell::from("Interface").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("Received").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("Transmitted").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Type").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("State").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Address").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("RX/s").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("TX/s").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Pkts/s RX/TX").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Total RX").style(Style::default().bold().fg(Color::DarkGray)),
+        Cell::from("Total TX").style(Style::default().bold().fg(Color::DarkGray)),
     ])
     .height(1);
 
+    // Rows between the header and the hint
+    let visible_height = chunks[0].height.saturating_sub(2) as usize;
+    let visible = scroll::visible_rows(tab, networks.len(), visible_height);
+
     let rows: Vec<Row> = networks
         .iter()
-        .map(|net| {
+        .enumerate()
+        .skip(visible.start)
+        .take(visible.len())
+        .map(|(i, net)| {
+            let style = if i == selected_item {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+
+            let state_style = match net.link.operstate.as_str() {
+                "up" => Style::default().fg(Color::Green),
+                "down" | "lowerlayerdown" => Style::default().fg(Color::Red),
+                _ => Style::default().fg(Color::DarkGray),
+            };
+            let address = net
+                .link
+                .ipv4
+                .first()
+                .or_else(|| net.link.ipv6.first())
+                .cloned()
+                .unwrap_or_else(|| "-".to_string());
+
             Row::new(vec![
                 Cell::from(net.name.clone()),
-                Cell::from(format_bytes(net.received)).style(Style::default().fg(Color::Green)),
-                Cell::from(format_bytes(net.transmitted)).style(Style::default().fg(Color::Yellow)),
+                Cell::from(net.link.kind.clone()),
+                Cell::from(net.link.operstate.clone()).style(state_style),
+                Cell::from(address),
+                Cell::from(format_rate(net.rx_rate)).style(Style::default().fg(Color::Green)),
+                Cell::from(format_rate(net.tx_rate)).style(Style::default().fg(Color::Yellow)),
+                Cell::from(format!("{:.0}/{:.0}", net.rx_packet_rate, net.tx_packet_rate)),
+                Cell::from(format_bytes(net.received)).style(Style::default().fg(Color::DarkGray)),
+                Cell::from(format_bytes(net.transmitted)).style(Style::default().fg(Color::DarkGray)),
             ])
+            .style(style)
         })
         .collect();
 
     let widths = [
-        Constraint::Percentage(40),
-        Constraint::Percentage(30),
-        Constraint::Percentage(30),
+        Constraint::Min(10),
+        Constraint::Length(9),
+        Constraint::Length(8),
+        Constraint::Length(20),
+        Constraint::Length(12),
+        Constraint::Length(12),
+        Constraint::Length(13),
+        Constraint::Length(11),
+        Constraint::Length(11),
     ];
 
     let table = Table::new(rows, widths)
         .header(header)
         .row_highlight_style(Style::default().bg(Color::DarkGray));
 
+    frame.render_widget(table, chunks[0]);
+    let rows_area = Rect {
+        y: chunks[0].y + 1,
+        height: visible_height as u16,
+        ..chunks[0]
+    };
+    scroll::draw_scrollbar(frame, rows_area, networks.len(), visible.start, visible_height);
+
+    // Show hint at bottom of the table
+    let hint = Paragraph::new(" Press Enter to view interface details | j/k to navigate ")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+
+    let hint_area = Rect {
+        x: chunks[0].x,
+        y: chunks[0].y + chunks[0].height.saturating_sub(1),
+        width: chunks[0].width,
+        height: 1,
+    };
+    frame.render_widget(hint, hint_area);
+
+    draw_errors(frame, app, chunks[1], selected_item);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[2]);
+
+    draw_protocol_counters(frame, app, bottom[1]);
+
+    // History charts for the selected interface
+    let Some(net) = networks.get(selected_item).or_else(|| networks.first()) else {
+        return;
+    };
+    let Some(history) = app.system_data.network_history.get(&net.name) else {
+        return;
+    };
+
+    // Wireless interfaces get a third chart for signal level
+    let wireless = net.wireless.as_ref();
+    let chart_count = if wireless.is_some() { 3 } else { 2 };
+    let charts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, chart_count); chart_count as usize])
+        .split(bottom[0]);
+
+    for (chart_area, (label, samples, color, current, peak)) in charts.iter().zip([
+        ("RX", &history.rx, Color::Green, net.rx_rate, net.peak_rx_rate),
+        ("TX", &history.tx, Color::Yellow, net.tx_rate, net.peak_tx_rate),
+    ]) {
+        // Show the newest samples that fit, right-aligned like a scrolling chart
+        let width = chart_area.width.saturating_sub(2) as usize;
+        let data: Vec<u64> = samples.iter().skip(samples.len().saturating_sub(width)).copied().collect();
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .title(format!(
+                        " {} {} {}  peak {} ",
+                        net.name,
+                        label,
+                        format_rate(current),
+                        format_rate(peak)
+                    )),
+            )
+            .data(&data)
+            .style(Style::default().fg(color));
+
+        frame.render_widget(sparkline, *chart_area);
+    }
+
+    if let (Some(wifi), Some(chart_area)) = (wireless, charts.get(2)) {
+        let width = chart_area.width.saturating_sub(2) as usize;
+        let samples = &history.signal;
+        let data: Vec<u64> = samples.iter().skip(samples.len().saturating_sub(width)).copied().collect();
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .title(format!(" {} Signal {} ", net.name, wireless_summary(wifi))),
+            )
+            .data(&data)
+            // Fixed -100..0 dBm scale so a weak link looks weak
+            .max(100)
+            .style(signal_style(wifi.signal_dbm));
+
+        frame.render_widget(sparkline, *chart_area);
+    }
+}
+
+/// One-line summary of a wireless link: signal, quality, SSID, frequency
+/// and bitrate, skipping whatever the driver does not report.
+fn wireless_summary(wifi: &WirelessInfo) -> String {
+    let mut parts = vec![match wifi.signal_dbm {
+        Some(dbm) => format!("{:.0} dBm", dbm),
+        None => "-".to_string(),
+    }];
+    parts.push(format!("quality {:.0}/{:.0}", wifi.quality, WirelessInfo::QUALITY_MAX));
+    if let Some(ssid) = &wifi.ssid {
+        parts.push(format!("\"{}\"", ssid));
+    }
+    if let Some(freq) = wifi.frequency {
+        parts.push(format!("{} MHz", freq));
+    }
+    if let Some(rate) = wifi.bitrate {
+        parts.push(format!("{:.1} Mb/s", rate));
+    }
+    parts.join("  ")
+}
+
+/// Green above -60 dBm, yellow down to -70 dBm, red below.
+pub fn signal_style(signal_dbm: Option<f32>) -> Style {
+    match signal_dbm {
+        Some(dbm) if dbm >= -60.0 => Style::default().fg(Color::Green),
+        Some(dbm) if dbm >= -70.0 => Style::default().fg(Color::Yellow),
+        Some(_) => Style::default().fg(Color::Red),
+        None => Style::default().fg(Color::DarkGray),
+    }
+}
+
+fn draw_errors(frame: &mut Frame, app: &App, area: Rect, selected_item: usize) {
+    let header = Row::new(vec![
+        Cell::from("Interface").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("RX errors").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("TX errors").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("RX drops").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("TX drops").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("RX FIFO").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("TX FIFO").style(Style::default().bold().fg(Color::Cyan)),
+        Cell::from("Collisions").style(Style::default().bold().fg(Color::Cyan)),
+    ])
+    .height(1);
+
+    let rows: Vec<Row> = app
+        .system_data
+        .network_list
+        .iter()
+        .enumerate()
+        .map(|(i, net)| {
+            let style = if i == selected_item {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            let e = &net.errors;
+
+            Row::new(vec![
+                Cell::from(net.name.clone()),
+                counter_cell(e.rx_errors),
+                counter_cell(e.tx_errors),
+                counter_cell(e.rx_dropped),
+                counter_cell(e.tx_dropped),
+                counter_cell(e.rx_fifo),
+                counter_cell(e.tx_fifo),
+                counter_cell(e.collisions),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(10),
+        Constraint::Length(13),
+        Constraint::Length(13),
+        Constraint::Length(13),
+        Constraint::Length(13),
+        Constraint::Length(13),
+        Constraint::Length(13),
+        Constraint::Length(13),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::TOP).title(" Errors & Drops (total, rate) "));
+
     frame.render_widget(table, area);
 }
+
+fn draw_protocol_counters(frame: &mut Frame, app: &App, area: Rect) {
+    let rows: Vec<Row> = app
+        .system_data
+        .protocol_counters
+        .iter()
+        .map(|p| Row::new(vec![Cell::from(p.label), counter_cell(p.counter)]))
+        .collect();
+
+    let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(14)])
+        .block(Block::default().borders(Borders::TOP).title(" Protocol Counters "));
+
+    frame.render_widget(table, area);
+}
+
+/// Shows a counter's total and current rate; red while it is still
+/// increasing, yellow if it ever fired.
+fn counter_cell(counter: Counter) -> Cell<'static> {
+    let style = if counter.rate > 0.0 {
+        Style::default().fg(Color::Red).bold()
+    } else if counter.total > 0 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let text = if counter.rate > 0.0 {
+        format!("{} +{:.1}/s", counter.total, counter.rate)
+    } else {
+        counter.total.to_string()
+    };
+
+    Cell::from(text).style(style)
+}
+
+pub fn format_rate(rate: f64) -> String {
+    format!("{}/s", format_bytes(rate as u64))
+}
@@ -8,7 +8,7 @@ use ratatui::{
 pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
     let info = &app.system_data.overview;
 
-    let lines = vec![
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("Hostname:       ", Style::default().fg(Color::Cyan)),
             Span::raw(&info.hostname),
@@ -40,14 +40,31 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect) {
             Span::styled("Total Memory:   ", Style::default().fg(Color::Cyan)),
             Span::raw(format_bytes(info.total_memory)),
         ]),
-        Line::from(""),
-        Line::from(""),
-        Line::from(Span::styled(
-            "Press ? for help",
-            Style::default().fg(Color::DarkGray),
-        )),
     ];
 
+    // Surface SMART problems so failing drives are noticed early
+    let smart_warnings = app.system_data.smart_warnings();
+    if !smart_warnings.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Drive Health Warnings:",
+            Style::default().bold().fg(Color::Red),
+        )));
+        for (device, warnings) in smart_warnings {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}: ", device), Style::default().fg(Color::Yellow)),
+                Span::styled(warnings.join(", "), Style::default().fg(Color::Red)),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press ? for help",
+        Style::default().fg(Color::DarkGray),
+    )));
+
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
 
     frame.render_widget(paragraph, area);
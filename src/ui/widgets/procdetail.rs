@@ -0,0 +1,214 @@
+use crate::app::{App, Tab};
+use crate::system::format_bytes;
+use crate::system::procdetail::ProcessDetail;
+use crate::system::threads;
+use crate::ui::widgets::scroll;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Sparkline},
+};
+
+/// Status fields worth showing up front; the rest follow in file order.
+const STATUS_KEYS: [&str; 10] = [
+    "State", "PPid", "Uid", "Gid", "Threads", "VmPeak", "VmRSS", "VmSwap", "voluntary_ctxt_switches",
+    "nonvoluntary_ctxt_switches",
+];
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let Some(detail) = &app.process_detail else {
+        frame.render_widget(Paragraph::new("Select a process in Processes and press Enter"), area);
+        scroll::clear(tab);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .split(area);
+
+    draw_charts(frame, detail, chunks[0]);
+
+    let lines = detail_lines(detail);
+    let (len, height) = (lines.len(), chunks[1].height as usize);
+    let offset = scroll::page_offset(tab, len, height);
+    frame.render_widget(Paragraph::new(lines).scroll((offset as u16, 0)), chunks[1]);
+    scroll::draw_scrollbar(frame, chunks[1], len, offset, height);
+}
+
+fn draw_charts(frame: &mut Frame, detail: &ProcessDetail, area: Rect) {
+    let charts = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(area);
+
+    let cpu_now = detail.cpu_history.back().copied().unwrap_or(0) as f64 / 10.0;
+    let memory_now = detail.memory_history.back().copied().unwrap_or(0);
+
+    for (chart_area, (title, samples, color)) in charts.iter().zip([
+        (format!(" CPU {:.1}% ", cpu_now), &detail.cpu_history, Color::Green),
+        (format!(" Memory {} ", format_bytes(memory_now)), &detail.memory_history, Color::Yellow),
+    ]) {
+        // Show the newest samples that fit, right-aligned like a scrolling chart
+        let width = chart_area.width.saturating_sub(2) as usize;
+        let data: Vec<u64> = samples.iter().skip(samples.len().saturating_sub(width)).copied().collect();
+
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(&data)
+            .style(Style::default().fg(color));
+        frame.render_widget(sparkline, *chart_area);
+    }
+}
+
+fn detail_lines(detail: &ProcessDetail) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![
+        Span::styled(format!("{} ", detail.name), Style::default().bold()),
+        Span::styled(format!("PID {}", detail.key.pid), Style::default().fg(Color::DarkGray)),
+        if detail.exited {
+            Span::styled("  (exited)", Style::default().fg(Color::Red).bold())
+        } else {
+            Span::raw("")
+        },
+    ])];
+
+    lines.push(field("Command", detail.cmdline.join(" ")));
+    lines.push(result_field("Executable", &detail.exe));
+    lines.push(result_field("Working dir", &detail.cwd));
+
+    section(&mut lines, "Status");
+    for key in STATUS_KEYS {
+        if let Some((_, value)) = detail.status.iter().find(|(k, _)| k == key) {
+            lines.push(field(key, value.clone()));
+        }
+    }
+    for (key, value) in detail.status.iter().filter(|(k, _)| !STATUS_KEYS.contains(&k.as_str())) {
+        lines.push(field(key, value.clone()));
+    }
+
+    section(&mut lines, &format!("Threads ({})", detail.threads.threads.len()));
+    lines.push(Line::from(Span::styled(
+        format!("  {:>8}  {:<16} {:<14} {:>6}  {}", "TID", "Name", "State", "CPU %", "Last CPU"),
+        Style::default().fg(Color::DarkGray),
+    )));
+    for thread in &detail.threads.threads {
+        lines.push(Line::from(format!(
+            "  {:>8}  {:<16} {:<14} {:>6.1}  {}",
+            thread.tid,
+            thread.name,
+            threads::state_name(thread.state),
+            thread.cpu_usage,
+            thread.processor
+        )));
+    }
+
+    section(&mut lines, "Resource limits");
+    lines.push(Line::from(Span::styled(
+        format!("  {:<26} {:>20} {:>20}  {}", "Limit", "Soft", "Hard", "Units"),
+        Style::default().fg(Color::DarkGray),
+    )));
+    for limit in &detail.limits {
+        lines.push(Line::from(format!(
+            "  {:<26} {:>20} {:>20}  {}",
+            limit.name, limit.soft, limit.hard, limit.units
+        )));
+    }
+
+    match &detail.maps {
+        Ok(maps) => {
+            section(&mut lines, &format!("Memory maps ({} regions, {} mapped)", maps.regions, format_bytes(maps.total)));
+            if !maps.rollup.is_empty() {
+                let rollup: Vec<String> = maps
+                    .rollup
+                    .iter()
+                    .filter(|(key, _)| ["Rss", "Pss", "Anonymous", "Swap"].contains(&key.as_str()))
+                    .map(|(key, bytes)| format!("{} {}", key, format_bytes(*bytes)))
+                    .collect();
+                lines.push(Line::from(format!("  {}", rollup.join("  "))));
+            }
+            for (kind, count, size) in &maps.kinds {
+                lines.push(Line::from(format!("  {:<12} {:>6} regions {:>12}", kind, count, format_bytes(*size))));
+            }
+            for (path, size) in &maps.largest_files {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("  {:>12}  ", format_bytes(*size))),
+                    Span::styled(path.clone(), Style::default().fg(Color::DarkGray)),
+                ]));
+            }
+        }
+        Err(e) => {
+            section(&mut lines, "Memory maps");
+            lines.push(error_line(e));
+        }
+    }
+
+    match &detail.fds {
+        Ok(fds) => {
+            section(&mut lines, &format!("Open files ({})", fds.len()));
+            for file in fds {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {:>5}  ", file.fd), Style::default().fg(Color::Yellow)),
+                    Span::raw(file.target.clone()),
+                ]));
+            }
+        }
+        Err(e) => {
+            section(&mut lines, "Open files");
+            lines.push(error_line(e));
+        }
+    }
+
+    section(&mut lines, "Cgroups");
+    for cgroup in &detail.cgroups {
+        lines.push(Line::from(format!("  {}", cgroup)));
+    }
+
+    section(&mut lines, "Namespaces");
+    for (name, target) in &detail.namespaces {
+        lines.push(field(name, target.clone()));
+    }
+
+    match &detail.environ {
+        Ok(vars) => {
+            section(&mut lines, &format!("Environment ({} variables)", vars.len()));
+            for var in vars {
+                let (key, value) = var.split_once('=').unwrap_or((var.as_str(), ""));
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}", key), Style::default().fg(Color::Yellow)),
+                    Span::raw(format!("={}", value)),
+                ]));
+            }
+        }
+        Err(e) => {
+            section(&mut lines, "Environment");
+            lines.push(error_line(e));
+        }
+    }
+
+    lines
+}
+
+fn section(lines: &mut Vec<Line<'static>>, title: &str) {
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(title.to_string(), Style::default().bold().fg(Color::Cyan))));
+}
+
+fn field(label: &str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("  {:<28}", label), Style::default().fg(Color::Yellow)),
+        Span::raw(value),
+    ])
+}
+
+fn result_field(label: &str, value: &Result<String, String>) -> Line<'static> {
+    match value {
+        Ok(value) => field(label, value.clone()),
+        Err(e) => Line::from(vec![
+            Span::styled(format!("  {:<28}", label), Style::default().fg(Color::Yellow)),
+            Span::styled(e.clone(), Style::default().fg(Color::DarkGray)),
+        ]),
+    }
+}
+
+fn error_line(error: &str) -> Line<'static> {
+    Line::from(Span::styled(format!("  {}", error), Style::default().fg(Color::Red)))
+}
@@ -1,86 +1,133 @@
-use crate::app::App;
-use crate::system::format_bytes;
+use crate::app::{App, Tab};
+use crate::ui::widgets::scroll;
+use crate::system::proctree::ProcessRow;
+use crate::system::threads::ThreadInfo;
+use crate::system::{format_bytes, format_start_time, ProcessColumn};
 use ratatui::{
     prelude::*,
-    widgets::{Cell, Paragraph, Row, Table},
+    widgets::{Cell, Clear, Paragraph, Row, Table},
 };
 
-pub fn draw(frame: &mut Frame, app: &App, area: Rect, scroll_offset: u16, selected_item: usize) {
-    let processes = &app.system_data.processes;
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let processes = app.process_rows();
+    let selected_item = tab.selected_item;
 
     if processes.is_empty() {
-        let paragraph = Paragraph::new("No processes found");
-        frame.render_widget(paragraph, area);
+        let message = if app.process_filter.is_empty() {
+            "No processes found"
+        } else {
+            "No processes match the filter (press / then Esc to clear it)"
+        };
+        frame.render_widget(Paragraph::new(message), area);
+        scroll::clear(tab);
         return;
     }
 
-    // Header
-    let header = Row::new(vec![
-        Cell::from("PID").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("Name").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("CPU %").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("Memory").style(Style::default().bold().fg(Color::Cyan)),
-        Cell::from("Status").style(Style::default().bold().fg(Color::Cyan)),
-    ])
-    .height(1);
+    let columns: Vec<ProcessColumn> = ProcessColumn::all()
+        .into_iter()
+        .filter(|c| app.process_columns.contains(c))
+        .collect();
+
+    // Header, marking the sorted column and direction
+    let header_style = Style::default().bold().fg(Color::Cyan);
+    let mut header_cells = Vec::new();
+    let mut widths = Vec::new();
+    for column in &columns {
+        let title = if *column == tab.sort_column {
+            format!("{} {}", column.title(), if tab.sort_ascending { "▲" } else { "▼" })
+        } else {
+            column.title().to_string()
+        };
+        header_cells.push(Cell::from(title).style(header_style));
+        widths.push(column_width(*column));
+
+        // The tree adds subtree totals next to the process's own memory
+        if app.process_tree && *column == ProcessColumn::Memory {
+            header_cells.push(Cell::from("Tree CPU %").style(header_style));
+            header_cells.push(Cell::from("Tree Mem").style(header_style));
+            widths.push(Constraint::Length(11));
+            widths.push(Constraint::Length(11));
+        }
+    }
+    let header = Row::new(header_cells).height(1);
 
     // Calculate visible range
-    let visible_height = area.height.saturating_sub(3) as usize; // account for header, hint, and borders
-    let offset = scroll_offset as usize;
-    let end = (offset + visible_height).min(processes.len());
-    let visible_processes = &processes[offset..end];
+    let visible_height = area.height.saturating_sub(4) as usize; // account for header, detail, hint, and borders
+    let visible = scroll::visible_rows(tab, processes.len(), visible_height);
+    let (offset, end) = (visible.start, visible.end);
+    let visible_processes = &processes[visible];
 
     let rows: Vec<Row> = visible_processes
         .iter()
         .enumerate()
-        .map(|(i, proc)| {
+        .map(|(i, row)| {
             let actual_index = offset + i;
             let is_selected = actual_index == selected_item;
 
+            let is_tagged = app.tagged_processes.contains(&row.proc.key());
+
             let base_style = if is_selected {
                 Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else if is_tagged {
+                Style::default().fg(Color::Yellow)
             } else {
                 Style::default()
             };
 
-            let cpu_style = if proc.cpu_usage >= 50.0 {
-                base_style.fg(Color::Red)
-            } else if proc.cpu_usage >= 20.0 {
-                base_style.fg(Color::Yellow)
-            } else if is_selected {
-                base_style.fg(Color::Green)
-            } else {
-                Style::default().fg(Color::Green)
-            };
+            if let Some(thread) = row.thread {
+                return thread_row(app, row, thread, &columns, is_selected);
+            }
 
-            Row::new(vec![
-                Cell::from(proc.pid.to_string()),
-                Cell::from(truncate_string(&proc.name, 25)),
-                Cell::from(format!("{:.1}", proc.cpu_usage)).style(cpu_style),
-                Cell::from(format_bytes(proc.memory)),
-                Cell::from(proc.status.clone()),
-            ])
-            .style(base_style)
+            let mut cells = Vec::new();
+            for column in &columns {
+                cells.push(cell(app, row, *column, base_style, is_selected, is_tagged));
+                if app.process_tree && *column == ProcessColumn::Memory {
+                    cells.push(Cell::from(format!("{:.1}", row.subtree_cpu)));
+                    cells.push(Cell::from(format_bytes(row.subtree_memory)));
+                }
+            }
+
+            Row::new(cells).style(base_style)
         })
         .collect();
 
-    let widths = [
-        Constraint::Length(8),
-        Constraint::Min(20),
-        Constraint::Length(8),
-        Constraint::Length(12),
-        Constraint::Length(10),
-    ];
-
     let table = Table::new(rows, widths)
         .header(header)
         .row_highlight_style(Style::default().bg(Color::DarkGray));
 
     frame.render_widget(table, area);
+    let rows_area = Rect {
+        y: area.y + 1,
+        height: visible_height as u16,
+        ..area
+    };
+    scroll::draw_scrollbar(frame, rows_area, processes.len(), offset, visible_height);
+
+    // Cumulative I/O and full command line for the selected process
+    if let Some(proc) = processes.get(selected_item).map(|row| row.proc) {
+        let detail = Paragraph::new(Line::from(vec![
+            Span::styled(format!(" {} ({}) ", proc.name, proc.pid), Style::default().fg(Color::Cyan)),
+            Span::raw(format!(
+                " Total read: {}  Total written: {}  ",
+                format_bytes(proc.total_read),
+                format_bytes(proc.total_written)
+            )),
+            Span::styled(proc.cmdline.clone(), Style::default().fg(Color::DarkGray)),
+        ]));
+
+        let detail_area = Rect {
+            x: area.x,
+            y: area.y + area.height.saturating_sub(2),
+            width: area.width,
+            height: 1,
+        };
+        frame.render_widget(Clear, detail_area);
+        frame.render_widget(detail, detail_area);
+    }
 
     // Show hint at bottom
     let hint = format!(
-        " [{}-{}/{}] j/k:navigate | x:kill | Tab:switch ",
+        " [{}-{}/{}] j/k:navigate | /:filter | s/S:sort | t:tree | -/+:fold | F2:columns | e/i/a:tune | H:threads | Space/T:tag | x:signal | K:signal tree ",
         offset + 1,
         end,
         processes.len()
@@ -98,10 +145,142 @@ pub fn draw(frame: &mut Frame, app: &App, area: Rect, scroll_offset: u16, select
     frame.render_widget(hint_widget, hint_area);
 }
 
+/// A thread listed under its expanded process. Only the columns that exist
+/// per thread are filled in.
+fn thread_row<'a>(app: &App, row: &ProcessRow, thread: &ThreadInfo, columns: &[ProcessColumn], is_selected: bool) -> Row<'a> {
+    let style = if is_selected {
+        Style::default().bg(Color::DarkGray).fg(Color::White)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let mut cells = Vec::new();
+    for column in columns {
+        cells.push(Cell::from(match column {
+            ProcessColumn::Pid => thread.tid.to_string(),
+            ProcessColumn::Name => {
+                let indent = if app.process_tree { "  ".repeat(row.depth) } else { "  ".to_string() };
+                format!("{}└ {}", indent, truncate_string(&thread.name, 25))
+            }
+            ProcessColumn::Cpu => format!("{:.1}", thread.cpu_usage),
+            // Short enough for the Status column; the detail view spells it out
+            ProcessColumn::Status => format!("{} cpu{}", thread.state, thread.processor),
+            _ => String::new(),
+        }));
+        if app.process_tree && *column == ProcessColumn::Memory {
+            cells.extend([Cell::from(""), Cell::from("")]);
+        }
+    }
+
+    Row::new(cells).style(style)
+}
+
+fn column_width(column: ProcessColumn) -> Constraint {
+    match column {
+        ProcessColumn::Pid => Constraint::Length(8),
+        ProcessColumn::Name => Constraint::Min(20),
+        ProcessColumn::User => Constraint::Length(12),
+        ProcessColumn::Cpu => Constraint::Length(8),
+        ProcessColumn::Memory | ProcessColumn::Virtual => Constraint::Length(12),
+        ProcessColumn::DiskRead | ProcessColumn::DiskWrite => Constraint::Length(12),
+        ProcessColumn::Threads => Constraint::Length(6),
+        ProcessColumn::Nice | ProcessColumn::Priority => Constraint::Length(5),
+        ProcessColumn::CpuTime | ProcessColumn::Runtime => Constraint::Length(11),
+        ProcessColumn::StartTime => Constraint::Length(8),
+        ProcessColumn::Tty => Constraint::Length(8),
+        ProcessColumn::Status => Constraint::Length(10),
+        ProcessColumn::Command => Constraint::Min(30),
+    }
+}
+
+fn cell<'a>(
+    app: &App,
+    row: &ProcessRow,
+    column: ProcessColumn,
+    base_style: Style,
+    is_selected: bool,
+    is_tagged: bool,
+) -> Cell<'a> {
+    let proc = row.proc;
+    let tag = if is_tagged { "* " } else { "" };
+
+    match column {
+        ProcessColumn::Pid => Cell::from(proc.pid.to_string()),
+        ProcessColumn::Name => {
+            // Indent by depth, marking subtrees that can be expanded or collapsed
+            if app.process_tree {
+                let marker = match (row.has_children, row.collapsed) {
+                    (true, true) => "▸ ",
+                    (true, false) => "▾ ",
+                    _ => "  ",
+                };
+                Cell::from(format!("{}{}{}{}", "  ".repeat(row.depth), marker, tag, truncate_string(&proc.name, 25)))
+            } else {
+                Cell::from(format!("{}{}", tag, truncate_string(&proc.name, 25)))
+            }
+        }
+        ProcessColumn::User => Cell::from(truncate_string(&proc.user, 12)),
+        ProcessColumn::Cpu => {
+            let cpu_style = if proc.cpu_usage >= 50.0 {
+                base_style.fg(Color::Red)
+            } else if proc.cpu_usage >= 20.0 {
+                base_style.fg(Color::Yellow)
+            } else if is_selected {
+                base_style.fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            Cell::from(format!("{:.1}", proc.cpu_usage)).style(cpu_style)
+        }
+        ProcessColumn::Memory => Cell::from(format_bytes(proc.memory)),
+        ProcessColumn::Virtual => Cell::from(format_bytes(proc.virtual_memory)),
+        ProcessColumn::DiskRead => Cell::from(format_rate(proc.read_rate)),
+        ProcessColumn::DiskWrite => Cell::from(format_rate(proc.write_rate)),
+        ProcessColumn::Threads => Cell::from(proc.threads.to_string()),
+        ProcessColumn::Nice => {
+            // Highlight processes that were deprioritized or boosted
+            let style = match proc.nice {
+                n if n > 0 => base_style.fg(Color::Blue),
+                n if n < 0 => base_style.fg(Color::Red),
+                _ => base_style,
+            };
+            Cell::from(proc.nice.to_string()).style(style)
+        }
+        ProcessColumn::Priority => Cell::from(proc.priority.to_string()),
+        ProcessColumn::CpuTime => Cell::from(format_runtime(proc.cpu_time as u64)),
+        ProcessColumn::StartTime => Cell::from(format_start_time(proc.start_time)),
+        ProcessColumn::Runtime => Cell::from(format_runtime(proc.run_time)),
+        ProcessColumn::Tty => Cell::from(proc.tty.clone().unwrap_or_else(|| "?".to_string())),
+        ProcessColumn::Status => Cell::from(proc.status.clone()),
+        ProcessColumn::Command => Cell::from(proc.cmdline.clone()),
+    }
+}
+
+fn format_rate(rate: f64) -> String {
+    if rate < 1.0 {
+        "-".to_string()
+    } else {
+        format!("{}/s", format_bytes(rate as u64))
+    }
+}
+
+/// Elapsed time like `ps -o etime`: `mm:ss`, `hh:mm:ss` or `Nd hh:mm`.
+fn format_runtime(seconds: u64) -> String {
+    let (days, hours, minutes, secs) = (seconds / 86400, (seconds % 86400) / 3600, (seconds % 3600) / 60, seconds % 60);
+    if days > 0 {
+        format!("{}d {:02}:{:02}", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, secs)
+    } else {
+        format!("{:02}:{:02}", minutes, secs)
+    }
+}
+
 fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    // Count characters, not bytes: names and users may be any UTF-8
+    if s.chars().count() <= max_len {
         s.to_string()
     } else {
-        format!("{}...", &s[..max_len - 3])
+        format!("{}...", s.chars().take(max_len - 3).collect::<String>())
     }
 }
@@ -0,0 +1,48 @@
+use crate::app::Tab;
+use ratatui::{
+    prelude::*,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+use std::ops::Range;
+
+/// Rows of a `len`-row list that fit in `height` rows, scrolled to keep the
+/// tab's selection visible. Records the list size on the tab so the
+/// navigation keys can clamp and page.
+pub fn visible_rows(tab: &Tab, len: usize, height: usize) -> Range<usize> {
+    tab.list_len.set(len);
+    tab.page_height.set(height);
+    let offset = tab.visible_offset(len, height);
+    offset..(offset + height).min(len)
+}
+
+/// Records that the tab has nothing to show, for views that return before
+/// drawing their list, so navigation doesn't clamp against a stale length.
+pub fn clear(tab: &Tab) {
+    tab.list_len.set(0);
+}
+
+/// Top line of a `len`-line page shown in `height` rows. Pages scroll a
+/// line at a time with the selection as the top line, stopping once the
+/// last line is visible.
+pub fn page_offset(tab: &Tab, len: usize, height: usize) -> usize {
+    let last = len.saturating_sub(height);
+    tab.list_len.set(last + 1);
+    tab.page_height.set(height);
+    tab.selected_item.min(last)
+}
+
+/// Draws a scrollbar along the right edge of `area` when a `len`-row list
+/// scrolled to `offset` doesn't fit in `height` rows.
+pub fn draw_scrollbar(frame: &mut Frame, area: Rect, len: usize, offset: usize, height: usize) {
+    if len <= height {
+        return;
+    }
+    let mut state = ScrollbarState::new(len - height + 1)
+        .position(offset)
+        .viewport_content_length(height);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_stateful_widget(scrollbar, area, &mut state);
+}
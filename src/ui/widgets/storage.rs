@@ -0,0 +1,123 @@
+use crate::app::{App, Tab};
+use crate::system::format_bytes;
+use crate::system::storage::StackNode;
+use crate::ui::widgets::scroll;
+use ratatui::{prelude::*, widgets::Paragraph};
+
+pub fn draw(frame: &mut Frame, app: &App, area: Rect, tab: &Tab) {
+    let storage = &app.system_data.storage;
+
+    if storage.arrays.is_empty() && storage.stacks.is_empty() {
+        let paragraph = Paragraph::new("No software RAID, LVM or device-mapper devices found");
+        frame.render_widget(paragraph, area);
+        scroll::clear(tab);
+        return;
+    }
+
+    let mut lines = Vec::new();
+
+    // Software RAID arrays from /proc/mdstat
+    if !storage.arrays.is_empty() {
+        lines.push(Line::from(Span::styled("RAID Arrays:", Style::default().bold())));
+
+        for array in &storage.arrays {
+            let (state_text, state_style) = if array.is_degraded() {
+                ("DEGRADED", Style::default().fg(Color::Red).bold())
+            } else if array.state == "active" {
+                ("active", Style::default().fg(Color::Green))
+            } else {
+                (array.state.as_str(), Style::default().fg(Color::Yellow))
+            };
+
+            let disks = if array.total_disks > 0 {
+                format!("[{}/{}] {}", array.total_disks, array.active_disks, array.status)
+            } else {
+                String::new()
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<8}", array.name), Style::default().fg(Color::Cyan)),
+                Span::raw(format!("{:<8}", array.level)),
+                Span::styled(format!("{:<10}", state_text), state_style),
+                Span::raw(disks),
+            ]));
+
+            let members: Vec<Span> = array
+                .members
+                .iter()
+                .flat_map(|m| {
+                    let (suffix, style) = if m.failed {
+                        (" (failed)", Style::default().fg(Color::Red))
+                    } else if m.spare {
+                        (" (spare)", Style::default().fg(Color::DarkGray))
+                    } else {
+                        ("", Style::default())
+                    };
+                    [Span::styled(format!("{}{}", m.name, suffix), style), Span::raw("  ")]
+                })
+                .collect();
+            lines.push(Line::from(
+                std::iter::once(Span::styled("    members: ", Style::default().fg(Color::DarkGray)))
+                    .chain(members)
+                    .collect::<Vec<_>>(),
+            ));
+
+            if let Some(sync) = &array.sync {
+                let mut text = format!("    {}", sync.action);
+                if let Some(percent) = sync.percent {
+                    text.push_str(&format!(" {:.1}%", percent));
+                }
+                if let Some(finish) = &sync.finish {
+                    text.push_str(&format!("  finish {}", finish));
+                }
+                if let Some(speed) = &sync.speed {
+                    text.push_str(&format!("  speed {}", speed));
+                }
+                lines.push(Line::from(Span::styled(text, Style::default().fg(Color::Yellow))));
+            }
+        }
+
+        lines.push(Line::from(""));
+    }
+
+    // Logical volumes, encrypted mappings and arrays with their backing devices
+    if !storage.stacks.is_empty() {
+        lines.push(Line::from(Span::styled("Storage Stack:", Style::default().bold())));
+        for node in &storage.stacks {
+            push_node(&mut lines, node, "  ", "");
+        }
+    }
+
+    let len = lines.len();
+    let offset = scroll::page_offset(tab, len, area.height as usize);
+    let paragraph = Paragraph::new(lines).scroll((offset as u16, 0));
+    frame.render_widget(paragraph, area);
+    scroll::draw_scrollbar(frame, area, len, offset, area.height as usize);
+}
+
+fn push_node(lines: &mut Vec<Line>, node: &StackNode, prefix: &str, branch: &str) {
+    let kind_style = match node.kind.as_str() {
+        "lvm" => Style::default().fg(Color::Green),
+        "crypt" => Style::default().fg(Color::Magenta),
+        "part" | "disk" => Style::default().fg(Color::DarkGray),
+        _ => Style::default().fg(Color::Yellow),
+    };
+
+    lines.push(Line::from(vec![
+        Span::raw(format!("{}{}", prefix, branch)),
+        Span::styled(node.name.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw("  "),
+        Span::styled(node.kind.clone(), kind_style),
+        Span::raw(format!("  {}", format_bytes(node.size))),
+    ]));
+
+    let child_prefix = match branch {
+        "├─ " => format!("{}│  ", prefix),
+        "└─ " => format!("{}   ", prefix),
+        _ => prefix.to_string(),
+    };
+    for (i, child) in node.children.iter().enumerate() {
+        let branch = if i + 1 == node.children.len() { "└─ " } else { "├─ " };
+        push_node(lines, child, &child_prefix, branch);
+    }
+}